@@ -44,6 +44,7 @@ fn main() {
     println!("cargo:rerun-if-changed=./CMakeLists.txt");
     println!("cargo:rerun-if-changed=./libquickfix");
     println!("cargo:rerun-if-changed=./quickfix-bind");
+    println!("cargo:rerun-if-changed=./libquickfix-patches");
 
     // Clone libquickfix to OUT_DIR because it modify itself when building
     let libquickfix_build_dir = Path::new(&out_dir).join("libquickfix");
@@ -51,6 +52,7 @@ fn main() {
     let _ = fs::remove_dir_all(&libquickfix_build_dir);
     fs_extra::copy_items(&["./libquickfix"], &out_dir, &CopyOptions::default())
         .expect("Fail to copy libquickfix");
+    apply_patches(&libquickfix_build_dir);
 
     // Build quickfix as a static library
     let mut quickfix_cmake_config = Config::new(libquickfix_build_dir);
@@ -146,6 +148,57 @@ fn update_sub_repositories() {
     }
 }
 
+/// Apply `libquickfix-patches/*.patch` in name order on the libquickfix copy.
+///
+/// Patches are plain unified diffs with `a/` and `b/` prefixes, applied in
+/// pure Rust so building does not require `git` or `patch` to be installed.
+fn apply_patches(libquickfix_dir: &Path) {
+    let mut patch_paths: Vec<_> = fs::read_dir("libquickfix-patches")
+        .expect("Fail to list patches")
+        .map(|entry| entry.expect("Fail to read patch entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "patch"))
+        .collect();
+    patch_paths.sort();
+
+    for patch_path in patch_paths {
+        let content = fs::read_to_string(&patch_path).expect("Fail to read patch");
+        for file_diff in split_file_diffs(&content) {
+            let patch = diffy::Patch::from_str(&file_diff)
+                .unwrap_or_else(|err| panic!("Invalid patch {}: {err}", patch_path.display()));
+            let target = patch
+                .modified()
+                .and_then(|name| name.strip_prefix("b/"))
+                .expect("Patched file name must start with b/");
+            let target_path = libquickfix_dir.join(target);
+
+            let base = fs::read_to_string(&target_path).unwrap_or_default();
+            let patched = diffy::apply(&base, &patch).unwrap_or_else(|err| {
+                panic!("Fail to apply {} on {target}: {err}", patch_path.display())
+            });
+            fs::write(&target_path, patched).expect("Fail to write patched file");
+        }
+    }
+}
+
+/// Split a multi files unified diff into one diff per file.
+fn split_file_diffs(content: &str) -> Vec<String> {
+    let lines: Vec<_> = content.split_inclusive('\n').collect();
+    let mut output: Vec<String> = Vec::new();
+
+    for (idx, line) in lines.iter().enumerate() {
+        let is_file_header = line.starts_with("--- ")
+            && lines
+                .get(idx + 1)
+                .is_some_and(|next| next.starts_with("+++ "));
+        match output.last_mut() {
+            Some(current) if !is_file_header => current.push_str(line),
+            _ => output.push(line.to_string()),
+        }
+    }
+
+    output
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum TargetOs {
     Windows,
@@ -36,6 +36,16 @@ static int8_t customFromApp(const void *data, const FixMessage_t *msg, const Fix
   return CALLBACK_OK;
 }
 
+static int8_t customOnDynamicSession(const void *data, const FixSessionID_t *session) {
+  printf("customOnDynamicSession: %p %p\n", data, session);
+  return CALLBACK_OK;
+}
+
+static int8_t customOnResend(const void *data, FixMessage_t *msg, const FixSessionID_t *session) {
+  printf("customOnResend: %p %p %p\n", data, msg, session);
+  return CALLBACK_OK;
+}
+
 static const FixApplicationCallbacks_t APP_CALLBACKS = {
     .onCreate = customOnCreate,
     .onLogon = customOnLogon,
@@ -44,6 +54,8 @@ static const FixApplicationCallbacks_t APP_CALLBACKS = {
     .toApp = customToApp,
     .fromAdmin = customFromAdmin,
     .fromApp = customFromApp,
+    .onDynamicSession = customOnDynamicSession,
+    .onResend = customOnResend,
 };
 
 static void customOnIncoming(const void *data, const FixSessionID_t *sessionId, const char *msg) {
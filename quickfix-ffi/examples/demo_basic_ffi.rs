@@ -54,6 +54,20 @@ extern "C" fn custom_from_app(
     CALLBACK_OK
 }
 
+extern "C" fn custom_on_dynamic_session(data: *const ffi::c_void, session: FixSessionID_t) -> i8 {
+    println!("custom_on_dynamic_session: {data:?} {session:?}");
+    CALLBACK_OK
+}
+
+extern "C" fn custom_on_resend(
+    data: *const ffi::c_void,
+    msg: FixMessage_t,
+    session: FixSessionID_t,
+) -> i8 {
+    println!("custom_on_resend: {data:?} {msg:?} {session:?}");
+    CALLBACK_OK
+}
+
 const APP_CALLBACKS: FixApplicationCallbacks_t = FixApplicationCallbacks_t {
     onCreate: custom_on_create,
     onLogon: custom_on_logon,
@@ -62,6 +76,8 @@ const APP_CALLBACKS: FixApplicationCallbacks_t = FixApplicationCallbacks_t {
     toApp: custom_to_app,
     fromAdmin: custom_from_admin,
     fromApp: custom_from_app,
+    onDynamicSession: custom_on_dynamic_session,
+    onResend: custom_on_resend,
 };
 
 extern "C" fn custom_on_incoming(
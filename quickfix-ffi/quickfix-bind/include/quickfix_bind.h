@@ -69,6 +69,11 @@ typedef struct Message FixMessage_t;
 typedef struct Header FixHeader_t;
 typedef struct Trailer FixTrailer_t;
 typedef struct Group FixGroup_t;
+typedef struct FieldIterator FixFieldIterator_t;
+
+typedef void (*FixWriteBytesCallback_t)(void *ctx, const char *data, uint64_t len);
+typedef void (*FixReadIntCallback_t)(void *ctx, int32_t value);
+typedef int8_t (*FixReadSessionIDCallback_t)(void *ctx, const FixSessionID_t *sessionId);
 
 typedef struct ApplicationCallbacks {
   void (*onCreate)(const void *data, const FixSessionID_t *session);
@@ -78,6 +83,8 @@ typedef struct ApplicationCallbacks {
   int8_t (*toApp)(const void *data, FixMessage_t *msg, const FixSessionID_t *session);
   int8_t (*fromAdmin)(const void *data, const FixMessage_t *msg, const FixSessionID_t *session);
   int8_t (*fromApp)(const void *data, const FixMessage_t *msg, const FixSessionID_t *session);
+  int8_t (*onDynamicSession)(const void *data, const FixSessionID_t *session);
+  int8_t (*onResend)(const void *data, FixMessage_t *msg, const FixSessionID_t *session);
 } FixApplicationCallbacks_t;
 
 typedef struct LogCallbacks {
@@ -96,6 +103,7 @@ const FixDictionary_t *FixSessionSettings_getGlobalRef(const FixSessionSettings_
 const FixDictionary_t *FixSessionSettings_getSessionRef(const FixSessionSettings_t *obj, const FixSessionID_t *id);
 int8_t FixSessionSettings_setGlobal(FixSessionSettings_t *obj, const FixDictionary_t *value);
 int8_t FixSessionSettings_setSession(FixSessionSettings_t *obj, const FixSessionID_t *id, const FixDictionary_t *value);
+int8_t FixSessionSettings_readSessions(const FixSessionSettings_t *obj, void *ctx, FixReadSessionIDCallback_t read);
 void FixSessionSettings_delete(const FixSessionSettings_t *obj);
 
 FixDictionary_t *FixDictionary_new(const char *name);
@@ -115,6 +123,22 @@ void FixDictionary_delete(const FixDictionary_t *obj);
 
 FixDataDictionary_t *FixDataDictionary_new();
 FixDataDictionary_t *FixDataDictionary_fromPath(const char *configPath);
+int8_t FixDataDictionary_validate(const FixDataDictionary_t *obj, const FixMessage_t *msg, int32_t *field);
+const char *FixDataDictionary_getFieldName(const FixDataDictionary_t *obj, int32_t tag);
+int32_t FixDataDictionary_getFieldTag(const FixDataDictionary_t *obj, const char *name);
+int32_t FixDataDictionary_getFieldType(const FixDataDictionary_t *obj, int32_t tag);
+int32_t FixFieldType_fromName(const char *name);
+const char *FixDataDictionary_getValueName(const FixDataDictionary_t *obj, int32_t tag, const char *value);
+int8_t FixDataDictionary_hasFieldValues(const FixDataDictionary_t *obj, int32_t tag);
+int8_t FixDataDictionary_isFieldValue(const FixDataDictionary_t *obj, int32_t tag, const char *value);
+int8_t FixDataDictionary_readFieldValues(const FixDataDictionary_t *obj, int32_t tag, void *ctx,
+                                         FixWriteBytesCallback_t read);
+int8_t FixDataDictionary_isRequiredField(const FixDataDictionary_t *obj, const char *msgType, int32_t tag);
+int8_t FixDataDictionary_readRequiredFields(const FixDataDictionary_t *obj, const char *msgType, void *ctx,
+                                            FixReadIntCallback_t read);
+int32_t FixDataDictionary_getGroupDelimiter(const FixDataDictionary_t *obj, const char *msgType, int32_t tag);
+int8_t FixDataDictionary_readGroupFields(const FixDataDictionary_t *obj, const char *msgType, int32_t tag, void *ctx,
+                                         FixReadIntCallback_t read);
 void FixDataDictionary_delete(const FixDataDictionary_t *obj);
 
 FixMessageStoreFactory_t *FixFileMessageStoreFactory_new(const FixSessionSettings_t *settings);
@@ -136,6 +160,8 @@ void FixLogFactory_delete(const FixLogFactory_t *obj);
 
 FixApplication_t *FixApplication_new(const void *data, const FixApplicationCallbacks_t *callbacks);
 void FixApplication_delete(const FixApplication_t *obj);
+void FixApplication_setRejectLogonText(const char *text);
+void FixApplication_skipAdminMessage();
 
 FixAcceptor_t *FixAcceptor_new(FixApplication_t *application, FixMessageStoreFactory_t *storeFactory,
                                const FixSessionSettings_t *settings, FixLogFactory_t *logFactory,
@@ -147,6 +173,11 @@ int8_t FixAcceptor_stop(FixAcceptor_t *obj);
 int8_t FixAcceptor_isLoggedOn(const FixAcceptor_t *obj);
 int8_t FixAcceptor_isStopped(const FixAcceptor_t *obj);
 FixSession_t *FixAcceptor_getSession(const FixAcceptor_t *obj, const FixSessionID_t *sessionId);
+FixDictionary_t *FixAcceptor_getSessionSettings(const FixAcceptor_t *obj, const FixSessionID_t *sessionId);
+int8_t FixAcceptor_readSessions(const FixAcceptor_t *obj, void *ctx, FixReadSessionIDCallback_t read);
+int8_t FixAcceptor_addSession(FixAcceptor_t *obj, const FixSessionID_t *sessionId, const FixDictionary_t *settings);
+int8_t FixAcceptor_removeSession(FixAcceptor_t *obj, const FixSessionID_t *sessionId);
+int8_t FixAcceptor_addTemplate(FixAcceptor_t *obj, const FixSessionID_t *pattern, const FixDictionary_t *settings);
 void FixAcceptor_delete(const FixAcceptor_t *obj);
 
 FixInitiator_t *FixInitiator_new(FixApplication_t *application, FixMessageStoreFactory_t *storeFactory,
@@ -159,6 +190,10 @@ int8_t FixInitiator_stop(FixInitiator_t *obj);
 int8_t FixInitiator_isLoggedOn(const FixInitiator_t *obj);
 int8_t FixInitiator_isStopped(const FixInitiator_t *obj);
 FixSession_t *FixInitiator_getSession(const FixInitiator_t *obj, const FixSessionID_t *sessionId);
+FixDictionary_t *FixInitiator_getSessionSettings(const FixInitiator_t *obj, const FixSessionID_t *sessionId);
+int8_t FixInitiator_readSessions(const FixInitiator_t *obj, void *ctx, FixReadSessionIDCallback_t read);
+int8_t FixInitiator_addSession(FixInitiator_t *obj, const FixSessionID_t *sessionId, const FixDictionary_t *settings);
+int8_t FixInitiator_removeSession(FixInitiator_t *obj, const FixSessionID_t *sessionId);
 void FixInitiator_delete(const FixInitiator_t *obj);
 
 FixSessionID_t *FixSessionID_new(const char *beginString, const char *senderCompID, const char *targetCompID,
@@ -175,13 +210,26 @@ void FixSessionID_delete(const FixSessionID_t *session);
 FixMessage_t *FixMessage_new();
 FixMessage_t *FixMessage_copy(const FixMessage_t *src);
 FixMessage_t *FixMessage_fromString(const char *text);
+FixMessage_t *FixMessage_fromBytes(const char *data, uint64_t len);
 FixMessage_t *FixMessage_fromStringAndDictionary(const char *text, const FixDataDictionary_t *dictionary);
+FixMessage_t *FixMessage_fromStringAndDictionaries(const char *text, const FixDataDictionary_t *sessionDictionary,
+                                                  const FixDataDictionary_t *applicationDictionary);
 const char *FixMessage_getField(const FixMessage_t *obj, int32_t tag);
 int8_t FixMessage_setField(FixMessage_t *obj, int32_t tag, const char *value);
+const char *FixMessage_getFieldBytes(const FixMessage_t *obj, int32_t tag, uint64_t *len);
+int8_t FixMessage_setFieldBytes(FixMessage_t *obj, int32_t tag, const char *value, uint64_t len);
 int8_t FixMessage_removeField(FixMessage_t *obj, int32_t tag);
 int8_t FixMessage_addGroup(FixMessage_t *obj, const FixGroup_t *group);
+int8_t FixMessage_removeGroup(FixMessage_t *obj, int32_t num, int32_t tag);
+int8_t FixMessage_replaceGroup(FixMessage_t *obj, int32_t num, const FixGroup_t *group);
+int8_t FixMessage_removeAllGroups(FixMessage_t *obj, int32_t tag);
+int64_t FixMessage_getGroupCount(const FixMessage_t *obj, int32_t tag);
 int64_t FixMessage_getStringLen(const FixMessage_t *obj);
 int8_t FixMessage_readString(const FixMessage_t *obj, char *buffer, uint64_t buffer_len);
+int8_t FixMessage_writeBytes(const FixMessage_t *obj, void *ctx, FixWriteBytesCallback_t write);
+int8_t FixMessage_isAdmin(const FixMessage_t *obj);
+int8_t FixMessage_isApp(const FixMessage_t *obj);
+int8_t FixMessage_reverseRoute(FixMessage_t *obj, const FixHeader_t *header);
 void FixMessage_delete(const FixMessage_t *obj);
 
 FixHeader_t *FixHeader_new();
@@ -190,8 +238,15 @@ FixHeader_t *FixMessage_copyHeader(const FixMessage_t *obj);
 FixHeader_t *FixMessage_getHeaderRef(FixMessage_t *obj);
 const char *FixHeader_getField(const FixHeader_t *obj, int32_t tag);
 int8_t FixHeader_setField(FixHeader_t *obj, int32_t tag, const char *value);
+const char *FixHeader_getFieldBytes(const FixHeader_t *obj, int32_t tag, uint64_t *len);
+int8_t FixHeader_setFieldBytes(FixHeader_t *obj, int32_t tag, const char *value, uint64_t len);
 int8_t FixHeader_removeField(FixHeader_t *obj, int32_t tag);
 int8_t FixHeader_addGroup(FixHeader_t *obj, const FixGroup_t *group);
+int8_t FixHeader_removeGroup(FixHeader_t *obj, int32_t num, int32_t tag);
+int8_t FixHeader_replaceGroup(FixHeader_t *obj, int32_t num, const FixGroup_t *group);
+int8_t FixHeader_removeAllGroups(FixHeader_t *obj, int32_t tag);
+int64_t FixHeader_getGroupCount(const FixHeader_t *obj, int32_t tag);
+int8_t FixHeader_writeBytes(const FixHeader_t *obj, void *ctx, FixWriteBytesCallback_t write);
 void FixHeader_delete(const FixHeader_t *obj);
 
 FixTrailer_t *FixTrailer_new();
@@ -200,8 +255,15 @@ FixTrailer_t *FixMessage_copyTrailer(const FixMessage_t *obj);
 FixTrailer_t *FixMessage_getTrailerRef(FixMessage_t *obj);
 const char *FixTrailer_getField(const FixTrailer_t *obj, int32_t tag);
 int8_t FixTrailer_setField(FixTrailer_t *obj, int32_t tag, const char *value);
+const char *FixTrailer_getFieldBytes(const FixTrailer_t *obj, int32_t tag, uint64_t *len);
+int8_t FixTrailer_setFieldBytes(FixTrailer_t *obj, int32_t tag, const char *value, uint64_t len);
 int8_t FixTrailer_removeField(FixTrailer_t *obj, int32_t tag);
 int8_t FixTrailer_addGroup(FixTrailer_t *obj, const FixGroup_t *group);
+int8_t FixTrailer_removeGroup(FixTrailer_t *obj, int32_t num, int32_t tag);
+int8_t FixTrailer_replaceGroup(FixTrailer_t *obj, int32_t num, const FixGroup_t *group);
+int8_t FixTrailer_removeAllGroups(FixTrailer_t *obj, int32_t tag);
+int64_t FixTrailer_getGroupCount(const FixTrailer_t *obj, int32_t tag);
+int8_t FixTrailer_writeBytes(const FixTrailer_t *obj, void *ctx, FixWriteBytesCallback_t write);
 void FixTrailer_delete(const FixTrailer_t *obj);
 
 FixGroup_t *FixGroup_new(int32_t fieldId, int32_t delim, const int32_t order[]);
@@ -215,15 +277,48 @@ int32_t FixGroup_getFieldId(const FixGroup_t *obj);
 int32_t FixGroup_getDelim(const FixGroup_t *obj);
 const char *FixGroup_getField(const FixGroup_t *obj, int32_t tag);
 int8_t FixGroup_setField(FixGroup_t *obj, int32_t tag, const char *value);
+const char *FixGroup_getFieldBytes(const FixGroup_t *obj, int32_t tag, uint64_t *len);
+int8_t FixGroup_setFieldBytes(FixGroup_t *obj, int32_t tag, const char *value, uint64_t len);
 int8_t FixGroup_removeField(FixGroup_t *obj, int32_t tag);
 int8_t FixGroup_addGroup(FixGroup_t *obj, const FixGroup_t *group);
+int8_t FixGroup_removeGroup(FixGroup_t *obj, int32_t num, int32_t tag);
+int8_t FixGroup_replaceGroup(FixGroup_t *obj, int32_t num, const FixGroup_t *group);
+int8_t FixGroup_removeAllGroups(FixGroup_t *obj, int32_t tag);
+int64_t FixGroup_getGroupCount(const FixGroup_t *obj, int32_t tag);
 void FixGroup_delete(const FixGroup_t *obj);
 
+FixFieldIterator_t *FixMessage_getFieldIterator(const FixMessage_t *obj);
+FixFieldIterator_t *FixHeader_getFieldIterator(const FixHeader_t *obj);
+FixFieldIterator_t *FixTrailer_getFieldIterator(const FixTrailer_t *obj);
+FixFieldIterator_t *FixGroup_getFieldIterator(const FixGroup_t *obj);
+int8_t FixFieldIterator_next(FixFieldIterator_t *obj);
+int32_t FixFieldIterator_getTag(const FixFieldIterator_t *obj);
+const char *FixFieldIterator_getValue(const FixFieldIterator_t *obj, uint64_t *len);
+int32_t FixFieldIterator_getDepth(const FixFieldIterator_t *obj);
+int8_t FixFieldIterator_isGroupStart(const FixFieldIterator_t *obj);
+void FixFieldIterator_delete(const FixFieldIterator_t *obj);
+
 int8_t FixSession_sendToTarget(FixMessage_t *msg, const FixSessionID_t *session_id);
 FixSession_t *FixSession_lookup(const FixSessionID_t *session_id);
 int8_t FixSession_logout(FixSession_t *session);
 int8_t FixSession_isLoggedOn(FixSession_t *session);
 int8_t FixSession_send(FixSession_t *session, FixMessage_t *msg);
+int8_t FixSession_sendResendRequest(FixSession_t *session, uint64_t beginSeqNo, uint64_t endSeqNo);
+int8_t FixSession_sendSequenceReset(FixSession_t *session, uint64_t newSeqNo, int8_t gapFill);
+int8_t FixSession_sendTestRequest(FixSession_t *session, const char *testReqId);
+int8_t FixSession_getExpectedSenderNum(FixSession_t *session, uint64_t *num);
+int8_t FixSession_getExpectedTargetNum(FixSession_t *session, uint64_t *num);
+int8_t FixSession_setNextSenderMsgSeqNum(FixSession_t *session, uint64_t num);
+int8_t FixSession_setNextTargetMsgSeqNum(FixSession_t *session, uint64_t num);
+int8_t FixSession_refresh(FixSession_t *session);
+int8_t FixSession_isEnabled(FixSession_t *session);
+int8_t FixSession_sentLogon(FixSession_t *session);
+int8_t FixSession_receivedLogon(FixSession_t *session);
+int8_t FixSession_isInitiator(FixSession_t *session);
+int8_t FixSession_isSessionTime(FixSession_t *session);
+int8_t FixSession_getHeartBtInt(FixSession_t *session, int32_t *value);
+FixSessionID_t *FixSession_getSessionID(FixSession_t *session);
+const char *FixSession_getCreationTime(FixSession_t *session);
 
 #ifdef __cplusplus
 }
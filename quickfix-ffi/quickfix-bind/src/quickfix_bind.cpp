@@ -1,6 +1,8 @@
 #include "quickfix_bind.h"
 
 #include <exception>
+#include <map>
+#include <mutex>
 
 #include <quickfix/Application.h>
 #include <quickfix/DataDictionary.h>
@@ -11,6 +13,7 @@
 #include <quickfix/Message.h>
 #include <quickfix/NullStore.h>
 #include <quickfix/Session.h>
+#include <quickfix/SessionFactory.h>
 #include <quickfix/SessionID.h>
 #include <quickfix/SessionSettings.h>
 #include <quickfix/SocketAcceptor.h>
@@ -218,6 +221,12 @@ void Fix_clearLastErrorMessage() {
   }
 }
 
+// Text of the logon rejected by the callback currently running on this thread, sent in the Logout answer.
+static thread_local std::string rejectLogonText;
+
+// Set when the callback currently running on this thread does not want the admin message to be sent.
+static thread_local bool skipAdminMessage = false;
+
 class ApplicationBind : public Application {
 private:
   const ApplicationCallbacks *callbacks;
@@ -252,7 +261,15 @@ public:
   void toAdmin(Message &msg, const SessionID &session) override {
     RETURN_IF_NULL(callbacks);
     RETURN_IF_NULL(callbacks->toAdmin);
+    skipAdminMessage = false;
     callbacks->toAdmin(data, &msg, &session);
+
+    // Session only catches IO errors when sending admin messages: message is dropped before being stored or sent,
+    // and the error text is logged as a session event.
+    if (skipAdminMessage) {
+      skipAdminMessage = false;
+      throw IOException("Admin message not sent by application");
+    }
   }
 
   void toApp(Message &msg, const SessionID &session) EXCEPT(DoNotSend) override {
@@ -264,6 +281,15 @@ public:
       throw DoNotSend();
   }
 
+  void onResend(Message &msg, const SessionID &session) EXCEPT(DoNotSend) override {
+    RETURN_IF_NULL(callbacks);
+    RETURN_IF_NULL(callbacks->onResend);
+    int8_t result = callbacks->onResend(data, &msg, &session);
+
+    if (result == CALLBACK_RESULT_DO_NOT_SEND)
+      throw DoNotSend();
+  }
+
   void fromAdmin(const Message &msg, const SessionID &session)
       EXCEPT(FieldNotFound, IncorrectDataFormat, IncorrectTagValue, RejectLogon) override {
     RETURN_IF_NULL(callbacks);
@@ -277,8 +303,11 @@ public:
       throw IncorrectDataFormat();
     case CALLBACK_RESULT_INCORRECT_TAG_VALUE:
       throw IncorrectTagValue();
-    case CALLBACK_RESULT_REJECT_LOGON:
-      throw RejectLogon();
+    case CALLBACK_RESULT_REJECT_LOGON: {
+      std::string text;
+      text.swap(rejectLogonText);
+      throw RejectLogon(text);
+    }
     }
   }
 
@@ -297,8 +326,16 @@ public:
       throw IncorrectTagValue();
     case CALLBACK_RESULT_REJECT_LOGON:
       throw RejectLogon();
+    case CALLBACK_RESULT_UNSUPPORTED_MESSAGE_TYPE:
+      throw UnsupportedMessageType();
     }
   }
+
+  bool onDynamicSession(const SessionID &session) {
+    RETURN_VAL_IF_NULL(callbacks, true);
+    RETURN_VAL_IF_NULL(callbacks->onDynamicSession, true);
+    return callbacks->onDynamicSession(data, &session) == CALLBACK_OK;
+  }
 };
 
 class ExternalLog : public Log {
@@ -365,6 +402,152 @@ public:
   void destroy(Log *log) override { delete log; }
 };
 
+// Create acceptor sessions from templates when an unknown counterparty sends its logon.
+class SessionTemplateProvider : public DynamicSessionProvider {
+private:
+  Application &application;
+  std::mutex mutex;
+  std::vector<std::pair<SessionID, Dictionary>> templates;
+
+  static bool matches(const std::string &pattern, const std::string &value) {
+    if (!pattern.empty() && pattern.back() == '*') {
+      return value.compare(0, pattern.size() - 1, pattern, 0, pattern.size() - 1) == 0;
+    }
+    return pattern == value;
+  }
+
+  const Dictionary *findTemplate(const SessionID &sessionId) const {
+    for (const auto &entry : templates) {
+      const SessionID &pattern = entry.first;
+      if (matches(pattern.getBeginString(), sessionId.getBeginString()) &&
+          matches(pattern.getSenderCompID(), sessionId.getSenderCompID()) &&
+          matches(pattern.getTargetCompID(), sessionId.getTargetCompID())) {
+        return &entry.second;
+      }
+    }
+    return nullptr;
+  }
+
+public:
+  SessionTemplateProvider(Application &application) : application(application) {}
+
+  SessionTemplateProvider(const SessionTemplateProvider &) = delete;
+  SessionTemplateProvider &operator=(const SessionTemplateProvider &) = delete;
+
+  virtual ~SessionTemplateProvider() {}
+
+  void addTemplate(const SessionID &pattern, const Dictionary &settings) {
+    std::lock_guard<std::mutex> lock(mutex);
+    templates.emplace_back(pattern, settings);
+  }
+
+  bool onUnknownSession(const SessionID &sessionId, Dictionary &settings) override {
+    {
+      std::lock_guard<std::mutex> lock(mutex);
+      const Dictionary *found = findTemplate(sessionId);
+      if (!found) {
+        return false;
+      }
+      settings = *found;
+    }
+
+    auto applicationBind = dynamic_cast<ApplicationBind *>(&application);
+    return !applicationBind || applicationBind->onDynamicSession(sessionId);
+  }
+};
+
+// Guard installing template provider of an acceptor.
+static std::mutex templateProviderMutex;
+
+// Per thread storage for strings returned to caller when C++ API only provides a copy.
+static thread_local std::string returnedString;
+
+// Per thread buffer used to serialize messages, so its allocation can be reused from one call to another.
+static thread_local std::string writeBuffer;
+
+// Dictionary that only knows standard FIX data fields, so their length prefix is used when parsing
+// and SOH can be part of their value.
+static const DataDictionary &dataFieldsDictionary() {
+  static const DataDictionary dictionary = [] {
+    DataDictionary value;
+    for (int field : {
+             FIELD::Signature,
+             FIELD::SecureData,
+             FIELD::RawData,
+             FIELD::XmlData,
+             FIELD::EncodedIssuer,
+             FIELD::EncodedSecurityDesc,
+             FIELD::EncodedListExecInst,
+             FIELD::EncodedText,
+             FIELD::EncodedSubject,
+             FIELD::EncodedHeadline,
+             FIELD::EncodedAllocText,
+             FIELD::EncodedUnderlyingIssuer,
+             FIELD::EncodedUnderlyingSecurityDesc,
+             FIELD::EncodedListStatusText,
+             FIELD::EncodedLegIssuer,
+             FIELD::EncodedLegSecurityDesc,
+             FIELD::EncryptedPassword,
+             FIELD::EncryptedNewPassword,
+         }) {
+      value.addFieldType(field, TYPE::Data);
+    }
+    return value;
+  }();
+  return dictionary;
+}
+
+class FieldIterator {
+private:
+  struct Position {
+    const FieldBase *field;
+    int32_t depth;
+    bool groupStart;
+  };
+
+  std::vector<Position> fields;
+  size_t position;
+  const Position *current;
+
+  void collect(const FieldMap &map, int32_t depth) {
+    // Follow `FieldMap::calculateString` so fields are visited in wire order.
+    bool groupStart = depth > 0;
+    for (auto const &field : map) {
+      fields.push_back({&field, depth, groupStart});
+      groupStart = false;
+
+      auto tagWithGroups = map.groups().find(field.getTag());
+      if (tagWithGroups == map.groups().end()) {
+        continue;
+      }
+
+      for (auto const &group : tagWithGroups->second) {
+        collect(*group, depth + 1);
+      }
+    }
+  }
+
+public:
+  FieldIterator(const FieldMap &map) : position(0), current(nullptr) { collect(map, 0); }
+
+  FieldIterator(const FieldIterator &) = delete;
+  FieldIterator &operator=(const FieldIterator &) = delete;
+
+  bool next() {
+    if (position >= fields.size()) {
+      current = nullptr;
+      return false;
+    }
+
+    current = &fields[position++];
+    return true;
+  }
+
+  const FieldBase *get() const { return current ? current->field : nullptr; }
+  int32_t depth() const { return current ? current->depth : 0; }
+  bool isGroupStart() const { return current && current->groupStart; }
+};
+
 SessionSettings *FixSessionSettings_new() {
   CATCH_OR_RETURN_NULL({ return new SessionSettings(); });
 }
@@ -405,6 +588,20 @@ int8_t FixSessionSettings_setSession(SessionSettings *obj, const SessionID *id,
   })
 }
 
+int8_t FixSessionSettings_readSessions(const SessionSettings *obj, void *ctx, FixReadSessionIDCallback_t read) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(read, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    for (const auto &sessionId : obj->getSessions()) {
+      int8_t code = read(ctx, &sessionId);
+      if (code != 0) {
+        return code;
+      }
+    }
+    return 0;
+  });
+}
+
 void FixSessionSettings_delete(const SessionSettings *obj) {
   RETURN_IF_NULL(obj);
   delete obj;
@@ -483,7 +680,7 @@ int8_t FixDictionary_readString(const Dictionary *obj, const char *key, char *bu
       return ERRNO_BUFFER_TO_SMALL;
     }
 
-    strncpy(buffer, value.c_str(), buffer_len);
+    memcpy(buffer, value.data(), value.size());
     buffer[value.size()] = '\0';
 
     return 0;
@@ -542,6 +739,224 @@ void FixDataDictionary_delete(const DataDictionary *obj) {
   delete obj;
 }
 
+int8_t FixDataDictionary_validate(const DataDictionary *obj, const Message *msg, int32_t *field) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msg, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(field, ERRNO_INVAL);
+
+  *field = 0;
+  CATCH_OR_RETURN_ERRNO({
+    // Catch exceptions with a field number to report it, then let caller macro handle them.
+    try {
+      obj->validate(*msg);
+    } catch (FieldNotFound &e) {
+      *field = e.field;
+      throw;
+    } catch (InvalidTagNumber &e) {
+      *field = e.field;
+      throw;
+    } catch (RequiredTagMissing &e) {
+      *field = e.field;
+      throw;
+    } catch (TagNotDefinedForMessage &e) {
+      *field = e.field;
+      throw;
+    } catch (NoTagValue &e) {
+      *field = e.field;
+      throw;
+    } catch (IncorrectTagValue &e) {
+      *field = e.field;
+      throw;
+    } catch (IncorrectDataFormat &e) {
+      *field = e.field;
+      throw;
+    } catch (TagOutOfOrder &e) {
+      *field = e.field;
+      throw;
+    } catch (RepeatedTag &e) {
+      *field = e.field;
+      throw;
+    } catch (RepeatingGroupCountMismatch &e) {
+      *field = e.field;
+      throw;
+    }
+    return 0;
+  });
+}
+
+const char *FixDataDictionary_getFieldName(const DataDictionary *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  CATCH_OR_RETURN_NULL({
+    if (!obj->getFieldName(tag, returnedString)) {
+      return NULL;
+    }
+    return returnedString.c_str();
+  });
+}
+
+int32_t FixDataDictionary_getFieldTag(const DataDictionary *obj, const char *name) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(name, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    int tag = 0;
+    obj->getFieldTag(name, tag);
+    return tag;
+  });
+}
+
+int32_t FixDataDictionary_getFieldType(const DataDictionary *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    TYPE::Type type = TYPE::Unknown;
+    obj->getFieldType(tag, type);
+    return type;
+  });
+}
+
+int32_t FixFieldType_fromName(const char *name) {
+  RETURN_VAL_IF_NULL(name, TYPE::Unknown);
+  static const std::map<std::string, TYPE::Type> types = {
+      {"String", TYPE::String},
+      {"Char", TYPE::Char},
+      {"Price", TYPE::Price},
+      {"Int", TYPE::Int},
+      {"Amt", TYPE::Amt},
+      {"Qty", TYPE::Qty},
+      {"Currency", TYPE::Currency},
+      {"MultipleValueString", TYPE::MultipleValueString},
+      {"MultipleStringValue", TYPE::MultipleStringValue},
+      {"MultipleCharValue", TYPE::MultipleCharValue},
+      {"Exchange", TYPE::Exchange},
+      {"UtcTimeStamp", TYPE::UtcTimeStamp},
+      {"Boolean", TYPE::Boolean},
+      {"LocalMktTime", TYPE::LocalMktTime},
+      {"LocalMktDate", TYPE::LocalMktDate},
+      {"Data", TYPE::Data},
+      {"Float", TYPE::Float},
+      {"PriceOffset", TYPE::PriceOffset},
+      {"MonthYear", TYPE::MonthYear},
+      {"DayOfMonth", TYPE::DayOfMonth},
+      {"UtcDateOnly", TYPE::UtcDateOnly},
+      {"UtcTimeOnly", TYPE::UtcTimeOnly},
+      {"NumInGroup", TYPE::NumInGroup},
+      {"Percentage", TYPE::Percentage},
+      {"SeqNum", TYPE::SeqNum},
+      {"TagNum", TYPE::TagNum},
+      {"Length", TYPE::Length},
+      {"Country", TYPE::Country},
+      {"TzTimeOnly", TYPE::TzTimeOnly},
+      {"TzTimeStamp", TYPE::TzTimeStamp},
+      {"XmlData", TYPE::XmlData},
+      {"Language", TYPE::Language},
+      {"Xid", TYPE::Xid},
+      {"XidRef", TYPE::XidRef},
+  };
+  auto type = types.find(name);
+  return type == types.end() ? TYPE::Unknown : type->second;
+}
+
+const char *FixDataDictionary_getValueName(const DataDictionary *obj, int32_t tag, const char *value) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(value, NULL);
+  CATCH_OR_RETURN_NULL({
+    if (!obj->getValueName(tag, value, returnedString)) {
+      return NULL;
+    }
+    return returnedString.c_str();
+  });
+}
+
+int8_t FixDataDictionary_hasFieldValues(const DataDictionary *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->hasFieldValue(tag); });
+}
+
+int8_t FixDataDictionary_isFieldValue(const DataDictionary *obj, int32_t tag, const char *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->isFieldValue(tag, value); });
+}
+
+int8_t FixDataDictionary_readFieldValues(const DataDictionary *obj, int32_t tag, void *ctx,
+                                         FixWriteBytesCallback_t read) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(read, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    for (const auto &value : obj->getFieldValues(tag)) {
+      read(ctx, value.data(), value.size());
+    }
+    return 0;
+  });
+}
+
+int8_t FixDataDictionary_isRequiredField(const DataDictionary *obj, const char *msgType, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msgType, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->isRequiredField(msgType, tag); });
+}
+
+int8_t FixDataDictionary_readRequiredFields(const DataDictionary *obj, const char *msgType, void *ctx,
+                                            FixReadIntCallback_t read) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msgType, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(read, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    for (int field : obj->getRequiredFields(msgType)) {
+      read(ctx, field);
+    }
+    return 0;
+  });
+}
+
+// Find group definition in message, looking recursively into nested groups.
+static bool findGroup(const DataDictionary &dictionary, const std::string &msgType, int tag, int &delim,
+                      const DataDictionary *&groupDictionary) {
+  if (dictionary.getGroup(msgType, tag, delim, groupDictionary)) {
+    return true;
+  }
+
+  for (int field : dictionary.getFieldsInOrder()) {
+    int fieldDelim;
+    const DataDictionary *fieldDictionary = nullptr;
+    if (dictionary.getGroup(msgType, field, fieldDelim, fieldDictionary) && fieldDictionary != &dictionary
+        && findGroup(*fieldDictionary, msgType, tag, delim, groupDictionary)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+int32_t FixDataDictionary_getGroupDelimiter(const DataDictionary *obj, const char *msgType, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msgType, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    int delim = 0;
+    const DataDictionary *groupDictionary = nullptr;
+    if (!findGroup(*obj, msgType, tag, delim, groupDictionary)) {
+      return 0;
+    }
+    return delim;
+  });
+}
+
+int8_t FixDataDictionary_readGroupFields(const DataDictionary *obj, const char *msgType, int32_t tag, void *ctx,
+                                         FixReadIntCallback_t read) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msgType, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(read, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    int delim = 0;
+    const DataDictionary *groupDictionary = nullptr;
+    if (!findGroup(*obj, msgType, tag, delim, groupDictionary)) {
+      return 0;
+    }
+    for (int field : groupDictionary->getFieldsInOrder()) {
+      read(ctx, field);
+    }
+    return 1;
+  });
+}
+
 MessageStoreFactory *FixFileMessageStoreFactory_new(const SessionSettings *settings) {
   RETURN_VAL_IF_NULL(settings, NULL);
   CATCH_OR_RETURN_NULL({ return new FileStoreFactory(*settings); });
@@ -593,6 +1008,10 @@ void FixApplication_delete(const Application *obj) {
   delete obj;
 }
 
+void FixApplication_setRejectLogonText(const char *text) { rejectLogonText = text ? text : ""; }
+
+void FixApplication_skipAdminMessage() { skipAdminMessage = true; }
+
 Acceptor *FixAcceptor_new(Application *application, MessageStoreFactory *storeFactory, const SessionSettings *settings,
                           LogFactory *logFactory, int8_t isMultiThreaded, int8_t isSslEnabled) {
   RETURN_VAL_IF_NULL(application, NULL);
@@ -658,9 +1077,82 @@ FixSession_t *FixAcceptor_getSession(const FixAcceptor_t *obj, const FixSessionI
   CATCH_OR_RETURN_NULL({ return obj->getSession(*sessionId); });
 }
 
+Dictionary *FixAcceptor_getSessionSettings(const FixAcceptor_t *obj, const FixSessionID_t *sessionId) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(sessionId, NULL);
+  CATCH_OR_RETURN_NULL({
+    const Dictionary *settings = obj->getSessionSettings(*sessionId);
+    RETURN_VAL_IF_NULL(settings, NULL);
+    return new Dictionary(*settings);
+  });
+}
+
+int8_t FixAcceptor_readSessions(const FixAcceptor_t *obj, void *ctx, FixReadSessionIDCallback_t read) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(read, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    for (const auto &sessionId : obj->getSessions()) {
+      int8_t code = read(ctx, &sessionId);
+      if (code != 0) {
+        return code;
+      }
+    }
+    return 0;
+  });
+}
+
+int8_t FixAcceptor_addSession(Acceptor *obj, const SessionID *sessionId, const Dictionary *settings) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(sessionId, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(settings, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->addSession(*sessionId, *settings);
+    return 0;
+  });
+}
+
+int8_t FixAcceptor_removeSession(Acceptor *obj, const SessionID *sessionId) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(sessionId, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->removeSession(*sessionId);
+    return 0;
+  });
+}
+
+int8_t FixAcceptor_addTemplate(Acceptor *obj, const SessionID *pattern, const Dictionary *settings) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(pattern, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(settings, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    // Check settings once merged with defaults, as they will be when the session is created.
+    Dictionary sessionSettings(*settings);
+    sessionSettings.merge(obj->getDefaultSettings());
+    if (sessionSettings.getString(CONNECTION_TYPE) != "acceptor") {
+      throw ConfigError(std::string(CONNECTION_TYPE) + " must be 'acceptor'");
+    }
+    sessionSettings.getInt(SOCKET_ACCEPT_PORT);
+
+    SessionTemplateProvider *provider;
+    {
+      // Concurrent calls must not each install their own provider.
+      std::lock_guard<std::mutex> lock(templateProviderMutex);
+      provider = dynamic_cast<SessionTemplateProvider *>(obj->getDynamicSessionProvider());
+      if (!provider) {
+        provider = new SessionTemplateProvider(obj->getApplication());
+        obj->setDynamicSessionProvider(provider);
+      }
+    }
+    provider->addTemplate(*pattern, *settings);
+    return 0;
+  });
+}
+
 void FixAcceptor_delete(const Acceptor *obj) {
   RETURN_IF_NULL(obj);
+  DynamicSessionProvider *provider = obj->getDynamicSessionProvider();
   delete obj;
+  delete provider;
 }
 
 Initiator *FixInitiator_new(Application *application, MessageStoreFactory *storeFactory,
@@ -729,6 +1221,49 @@ FixSession_t *FixInitiator_getSession(const FixInitiator_t *obj, const FixSessio
   CATCH_OR_RETURN_NULL({ return obj->getSession(*sessionId); });
 }
 
+Dictionary *FixInitiator_getSessionSettings(const FixInitiator_t *obj, const FixSessionID_t *sessionId) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(sessionId, NULL);
+  CATCH_OR_RETURN_NULL({
+    const Dictionary *settings = obj->getSessionSettings(*sessionId);
+    RETURN_VAL_IF_NULL(settings, NULL);
+    return new Dictionary(*settings);
+  });
+}
+
+int8_t FixInitiator_readSessions(const FixInitiator_t *obj, void *ctx, FixReadSessionIDCallback_t read) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(read, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    for (const auto &sessionId : obj->getSessions()) {
+      int8_t code = read(ctx, &sessionId);
+      if (code != 0) {
+        return code;
+      }
+    }
+    return 0;
+  });
+}
+
+int8_t FixInitiator_addSession(Initiator *obj, const SessionID *sessionId, const Dictionary *settings) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(sessionId, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(settings, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->addSession(*sessionId, *settings);
+    return 0;
+  });
+}
+
+int8_t FixInitiator_removeSession(Initiator *obj, const SessionID *sessionId) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(sessionId, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->removeSession(*sessionId);
+    return 0;
+  });
+}
+
 void FixInitiator_delete(const Initiator *obj) {
   RETURN_IF_NULL(obj);
   delete obj;
@@ -794,7 +1329,12 @@ Message *FixMessage_copy(const Message *src) {
 
 Message *FixMessage_fromString(const char *text) {
   RETURN_VAL_IF_NULL(text, NULL);
-  CATCH_OR_RETURN_NULL({ return new Message(text, /* validate = */ false); });
+  CATCH_OR_RETURN_NULL({ return new Message(text, dataFieldsDictionary(), /* validate = */ false); });
+}
+
+Message *FixMessage_fromBytes(const char *data, uint64_t len) {
+  RETURN_VAL_IF_NULL(data, NULL);
+  CATCH_OR_RETURN_NULL({ return new Message(std::string(data, len), dataFieldsDictionary(), /* validate = */ false); });
 }
 
 Message *FixMessage_fromStringAndDictionary(const char *text, const DataDictionary *dictionary) {
@@ -804,6 +1344,16 @@ Message *FixMessage_fromStringAndDictionary(const char *text, const DataDictiona
   CATCH_OR_RETURN_NULL({ return new Message(text, *dictionary, /* validate = */ true); });
 }
 
+Message *FixMessage_fromStringAndDictionaries(const char *text, const DataDictionary *sessionDictionary,
+                                             const DataDictionary *applicationDictionary) {
+  RETURN_VAL_IF_NULL(text, NULL);
+  RETURN_VAL_IF_NULL(sessionDictionary, NULL);
+  RETURN_VAL_IF_NULL(applicationDictionary, NULL);
+
+  CATCH_OR_RETURN_NULL(
+      { return new Message(text, *sessionDictionary, *applicationDictionary, /* validate = */ true); });
+}
+
 const char *FixMessage_getField(const Message *obj, int32_t tag) {
   RETURN_VAL_IF_NULL(obj, NULL);
   CATCH_OR_RETURN_NULL({ return obj->getField(tag).c_str(); });
@@ -818,6 +1368,25 @@ int8_t FixMessage_setField(Message *obj, int32_t tag, const char *value) {
   });
 }
 
+const char *FixMessage_getFieldBytes(const Message *obj, int32_t tag, uint64_t *len) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(len, NULL);
+  CATCH_OR_RETURN_NULL({
+    const std::string &value = obj->getField(tag);
+    *len = value.size();
+    return value.data();
+  });
+}
+
+int8_t FixMessage_setFieldBytes(Message *obj, int32_t tag, const char *value, uint64_t len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->setField(tag, std::string(value, len));
+    return 0;
+  });
+}
+
 int8_t FixMessage_removeField(Message *obj, int32_t tag) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -835,6 +1404,44 @@ int8_t FixMessage_addGroup(Message *obj, const Group *group) {
   })
 }
 
+int8_t FixMessage_removeGroup(Message *obj, int32_t num, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    if (num <= 0) {
+      throw FieldNotFound(tag);
+    }
+    obj->getGroupRef(num, tag); // Throw if group does not exist.
+    obj->FieldMap::removeGroup(num, tag);
+    return 0;
+  })
+}
+
+int8_t FixMessage_replaceGroup(Message *obj, int32_t num, const Group *group) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(group, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    if (num <= 0) {
+      throw FieldNotFound(group->field());
+    }
+    obj->getGroupRef(num, group->field()); // Throw if group does not exist.
+    obj->FieldMap::replaceGroup(num, group->field(), *group);
+    return 0;
+  })
+}
+
+int8_t FixMessage_removeAllGroups(Message *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->FieldMap::removeGroup(tag);
+    return 0;
+  })
+}
+
+int64_t FixMessage_getGroupCount(const Message *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->groupCount(tag); })
+}
+
 int64_t FixMessage_getStringLen(const FixMessage_t *obj) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
 
@@ -851,13 +1458,43 @@ int8_t FixMessage_readString(const FixMessage_t *obj, char *buffer, uint64_t buf
       return ERRNO_BUFFER_TO_SMALL;
     }
 
-    strncpy(buffer, value.c_str(), buffer_len);
+    memcpy(buffer, value.data(), value.size());
     buffer[value.size()] = '\0';
 
     return 0;
   })
 }
 
+int8_t FixMessage_writeBytes(const Message *obj, void *ctx, FixWriteBytesCallback_t write) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(write, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    obj->toString(writeBuffer);
+    write(ctx, writeBuffer.data(), writeBuffer.size());
+    return 0;
+  })
+}
+
+int8_t FixMessage_isAdmin(const Message *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->isAdmin(); });
+}
+
+int8_t FixMessage_isApp(const Message *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->isApp(); });
+}
+
+int8_t FixMessage_reverseRoute(Message *obj, const Header *header) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(header, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->reverseRoute(*header);
+    return 0;
+  });
+}
+
 void FixMessage_delete(const Message *obj) {
   RETURN_IF_NULL(obj);
   delete obj;
@@ -896,6 +1533,25 @@ int8_t FixHeader_setField(Header *obj, int32_t tag, const char *value) {
   });
 }
 
+const char *FixHeader_getFieldBytes(const Header *obj, int32_t tag, uint64_t *len) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(len, NULL);
+  CATCH_OR_RETURN_NULL({
+    const std::string &value = obj->getField(tag);
+    *len = value.size();
+    return value.data();
+  });
+}
+
+int8_t FixHeader_setFieldBytes(Header *obj, int32_t tag, const char *value, uint64_t len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->setField(tag, std::string(value, len));
+    return 0;
+  });
+}
+
 int8_t FixHeader_removeField(Header *obj, int32_t tag) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -913,6 +1569,56 @@ int8_t FixHeader_addGroup(Header *obj, const Group *group) {
   })
 }
 
+int8_t FixHeader_removeGroup(Header *obj, int32_t num, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    if (num <= 0) {
+      throw FieldNotFound(tag);
+    }
+    obj->getGroupRef(num, tag); // Throw if group does not exist.
+    obj->FieldMap::removeGroup(num, tag);
+    return 0;
+  })
+}
+
+int8_t FixHeader_replaceGroup(Header *obj, int32_t num, const Group *group) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(group, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    if (num <= 0) {
+      throw FieldNotFound(group->field());
+    }
+    obj->getGroupRef(num, group->field()); // Throw if group does not exist.
+    obj->FieldMap::replaceGroup(num, group->field(), *group);
+    return 0;
+  })
+}
+
+int8_t FixHeader_removeAllGroups(Header *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->FieldMap::removeGroup(tag);
+    return 0;
+  })
+}
+
+int64_t FixHeader_getGroupCount(const Header *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->groupCount(tag); })
+}
+
+int8_t FixHeader_writeBytes(const Header *obj, void *ctx, FixWriteBytesCallback_t write) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(write, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    writeBuffer.clear();
+    obj->calculateString(writeBuffer);
+    write(ctx, writeBuffer.data(), writeBuffer.size());
+    return 0;
+  })
+}
+
 void FixHeader_delete(const Header *obj) {
   RETURN_IF_NULL(obj);
   delete obj;
@@ -951,6 +1657,25 @@ int8_t FixTrailer_setField(Trailer *obj, int32_t tag, const char *value) {
   });
 }
 
+const char *FixTrailer_getFieldBytes(const Trailer *obj, int32_t tag, uint64_t *len) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(len, NULL);
+  CATCH_OR_RETURN_NULL({
+    const std::string &value = obj->getField(tag);
+    *len = value.size();
+    return value.data();
+  });
+}
+
+int8_t FixTrailer_setFieldBytes(Trailer *obj, int32_t tag, const char *value, uint64_t len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->setField(tag, std::string(value, len));
+    return 0;
+  });
+}
+
 int8_t FixTrailer_removeField(Trailer *obj, int32_t tag) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -968,6 +1693,56 @@ int8_t FixTrailer_addGroup(Trailer *obj, const Group *group) {
   })
 }
 
+int8_t FixTrailer_removeGroup(Trailer *obj, int32_t num, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    if (num <= 0) {
+      throw FieldNotFound(tag);
+    }
+    obj->getGroupRef(num, tag); // Throw if group does not exist.
+    obj->FieldMap::removeGroup(num, tag);
+    return 0;
+  })
+}
+
+int8_t FixTrailer_replaceGroup(Trailer *obj, int32_t num, const Group *group) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(group, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    if (num <= 0) {
+      throw FieldNotFound(group->field());
+    }
+    obj->getGroupRef(num, group->field()); // Throw if group does not exist.
+    obj->FieldMap::replaceGroup(num, group->field(), *group);
+    return 0;
+  })
+}
+
+int8_t FixTrailer_removeAllGroups(Trailer *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->FieldMap::removeGroup(tag);
+    return 0;
+  })
+}
+
+int64_t FixTrailer_getGroupCount(const Trailer *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->groupCount(tag); })
+}
+
+int8_t FixTrailer_writeBytes(const Trailer *obj, void *ctx, FixWriteBytesCallback_t write) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(write, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    writeBuffer.clear();
+    obj->calculateString(writeBuffer);
+    write(ctx, writeBuffer.data(), writeBuffer.size());
+    return 0;
+  })
+}
+
 void FixTrailer_delete(const Trailer *obj) {
   RETURN_IF_NULL(obj);
   delete obj;
@@ -1044,6 +1819,25 @@ int8_t FixGroup_setField(Group *obj, int32_t tag, const char *value) {
   });
 }
 
+const char *FixGroup_getFieldBytes(const Group *obj, int32_t tag, uint64_t *len) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(len, NULL);
+  CATCH_OR_RETURN_NULL({
+    const std::string &value = obj->getField(tag);
+    *len = value.size();
+    return value.data();
+  });
+}
+
+int8_t FixGroup_setFieldBytes(Group *obj, int32_t tag, const char *value, uint64_t len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->setField(tag, std::string(value, len));
+    return 0;
+  });
+}
+
 int8_t FixGroup_removeField(Group *obj, int32_t tag) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -1061,11 +1855,106 @@ int8_t FixGroup_addGroup(Group *obj, const Group *group) {
   })
 }
 
+int8_t FixGroup_removeGroup(Group *obj, int32_t num, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    if (num <= 0) {
+      throw FieldNotFound(tag);
+    }
+    obj->getGroupRef(num, tag); // Throw if group does not exist.
+    obj->FieldMap::removeGroup(num, tag);
+    return 0;
+  })
+}
+
+int8_t FixGroup_replaceGroup(Group *obj, int32_t num, const Group *group) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(group, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    if (num <= 0) {
+      throw FieldNotFound(group->field());
+    }
+    obj->getGroupRef(num, group->field()); // Throw if group does not exist.
+    obj->FieldMap::replaceGroup(num, group->field(), *group);
+    return 0;
+  })
+}
+
+int8_t FixGroup_removeAllGroups(Group *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->FieldMap::removeGroup(tag);
+    return 0;
+  })
+}
+
+int64_t FixGroup_getGroupCount(const Group *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->groupCount(tag); })
+}
+
 void FixGroup_delete(const Group *obj) {
   RETURN_IF_NULL(obj);
   delete obj;
 }
 
+FieldIterator *FixMessage_getFieldIterator(const Message *obj) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  CATCH_OR_RETURN_NULL({ return new FieldIterator(*obj); });
+}
+
+FieldIterator *FixHeader_getFieldIterator(const Header *obj) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  CATCH_OR_RETURN_NULL({ return new FieldIterator(*obj); });
+}
+
+FieldIterator *FixTrailer_getFieldIterator(const Trailer *obj) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  CATCH_OR_RETURN_NULL({ return new FieldIterator(*obj); });
+}
+
+FieldIterator *FixGroup_getFieldIterator(const Group *obj) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  CATCH_OR_RETURN_NULL({ return new FieldIterator(*obj); });
+}
+
+int8_t FixFieldIterator_next(FieldIterator *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->next(); });
+}
+
+int32_t FixFieldIterator_getTag(const FieldIterator *obj) {
+  RETURN_VAL_IF_NULL(obj, 0);
+  RETURN_VAL_IF_NULL(obj->get(), 0);
+  CATCH_OR_RETURN(0, { return obj->get()->getTag(); });
+}
+
+const char *FixFieldIterator_getValue(const FieldIterator *obj, uint64_t *len) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(obj->get(), NULL);
+  RETURN_VAL_IF_NULL(len, NULL);
+  CATCH_OR_RETURN_NULL({
+    const std::string &value = obj->get()->getString();
+    *len = value.size();
+    return value.data();
+  });
+}
+
+int32_t FixFieldIterator_getDepth(const FieldIterator *obj) {
+  RETURN_VAL_IF_NULL(obj, 0);
+  return obj->depth();
+}
+
+int8_t FixFieldIterator_isGroupStart(const FieldIterator *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  return obj->isGroupStart();
+}
+
+void FixFieldIterator_delete(const FieldIterator *obj) {
+  RETURN_IF_NULL(obj);
+  delete obj;
+}
+
 int8_t FixSession_sendToTarget(Message *msg, const SessionID *session_id) {
   RETURN_VAL_IF_NULL(msg, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(session_id, ERRNO_INVAL);
@@ -1099,6 +1988,34 @@ int8_t FixSession_send(FixSession_t *session, FixMessage_t *msg) {
   CATCH_OR_RETURN_ERRNO({ return session->send(*msg); });
 }
 
+int8_t FixSession_sendResendRequest(FixSession_t *session, uint64_t beginSeqNo, uint64_t endSeqNo) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    Message resendRequest;
+    resendRequest.getHeader().setField(MsgType(MsgType_ResendRequest));
+    resendRequest.setField(BeginSeqNo(beginSeqNo));
+    resendRequest.setField(EndSeqNo(endSeqNo));
+    return session->send(resendRequest);
+  });
+}
+
+int8_t FixSession_sendSequenceReset(FixSession_t *session, uint64_t newSeqNo, int8_t gapFill) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  // Skipped sequence numbers are gap filled if counterparty asks to resend them.
+  CATCH_OR_RETURN_ERRNO({ return session->sendSequenceReset(newSeqNo, gapFill != 0); });
+}
+
+int8_t FixSession_sendTestRequest(FixSession_t *session, const char *testReqId) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(testReqId, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    Message testRequest;
+    testRequest.getHeader().setField(MsgType(MsgType_TestRequest));
+    testRequest.setField(TestReqID(testReqId));
+    return session->send(testRequest);
+  });
+}
+
 int8_t FixSession_reset(FixSession_t *session) {
   RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -1115,5 +2032,94 @@ int8_t FixSession_logon(FixSession_t *session) {
   });
 }
 
+int8_t FixSession_getExpectedSenderNum(FixSession_t *session, uint64_t *num) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(num, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    *num = session->getExpectedSenderNum();
+    return 0;
+  });
+}
+
+int8_t FixSession_getExpectedTargetNum(FixSession_t *session, uint64_t *num) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(num, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    *num = session->getExpectedTargetNum();
+    return 0;
+  });
+}
+
+int8_t FixSession_setNextSenderMsgSeqNum(FixSession_t *session, uint64_t num) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    session->setNextSenderMsgSeqNum(num);
+    return 0;
+  });
+}
+
+int8_t FixSession_setNextTargetMsgSeqNum(FixSession_t *session, uint64_t num) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    session->setNextTargetMsgSeqNum(num);
+    return 0;
+  });
+}
+
+int8_t FixSession_refresh(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    session->refresh();
+    return 0;
+  });
+}
+
+int8_t FixSession_isEnabled(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return session->isEnabled(); });
+}
+
+int8_t FixSession_sentLogon(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return session->sentLogon(); });
+}
+
+int8_t FixSession_receivedLogon(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return session->receivedLogon(); });
+}
+
+int8_t FixSession_isInitiator(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return session->isInitiator(); });
+}
+
+int8_t FixSession_isSessionTime(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return session->isSessionTime(UtcTimeStamp::now()); });
+}
+
+int8_t FixSession_getHeartBtInt(FixSession_t *session, int32_t *value) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    *value = session->getHeartBtInt();
+    return 0;
+  });
+}
+
+SessionID *FixSession_getSessionID(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, NULL);
+  CATCH_OR_RETURN_NULL({ return new SessionID(session->getSessionID()); });
+}
+
+const char *FixSession_getCreationTime(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, NULL);
+  CATCH_OR_RETURN_NULL({
+    returnedString = UtcTimeStampConvertor::convert(session->getStore()->getCreationTime(), 9);
+    return returnedString.c_str();
+  });
+}
+
 } // namespace FIX
 } // extern C
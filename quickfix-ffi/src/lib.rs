@@ -74,6 +74,18 @@ pub struct FixTrailer_t(NonNull<ffi::c_void>);
 #[repr(transparent)]
 pub struct FixGroup_t(NonNull<ffi::c_void>);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FixFieldIterator_t(NonNull<ffi::c_void>);
+
+pub type FixWriteBytesCallback_t =
+    extern "C" fn(ctx: *mut ffi::c_void, data: *const ffi::c_char, len: u64);
+
+pub type FixReadIntCallback_t = extern "C" fn(ctx: *mut ffi::c_void, value: i32);
+
+pub type FixReadSessionIDCallback_t =
+    extern "C" fn(ctx: *mut ffi::c_void, sessionId: FixSessionID_t) -> i8;
+
 #[derive(Debug, Clone, Copy)]
 #[repr(C)]
 pub struct FixApplicationCallbacks_t {
@@ -84,6 +96,8 @@ pub struct FixApplicationCallbacks_t {
     pub toApp: extern "C" fn(*const ffi::c_void, FixMessage_t, FixSessionID_t) -> i8,
     pub fromAdmin: extern "C" fn(*const ffi::c_void, FixMessage_t, FixSessionID_t) -> i8,
     pub fromApp: extern "C" fn(*const ffi::c_void, FixMessage_t, FixSessionID_t) -> i8,
+    pub onDynamicSession: extern "C" fn(*const ffi::c_void, FixSessionID_t) -> i8,
+    pub onResend: extern "C" fn(*const ffi::c_void, FixMessage_t, FixSessionID_t) -> i8,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -142,6 +156,13 @@ extern "C" {
         value: FixDictionary_t,
     ) -> i8;
 
+    #[must_use]
+    pub fn FixSessionSettings_readSessions(
+        obj: FixSessionSettings_t,
+        ctx: *mut ffi::c_void,
+        read: FixReadSessionIDCallback_t,
+    ) -> i8;
+
     pub fn FixSessionSettings_delete(obj: FixSessionSettings_t);
 
     // Dictionary
@@ -200,6 +221,76 @@ extern "C" {
 
     pub fn FixDataDictionary_delete(obj: FixDataDictionary_t);
 
+    #[must_use]
+    pub fn FixDataDictionary_validate(
+        obj: FixDataDictionary_t,
+        msg: FixMessage_t,
+        field: *mut i32,
+    ) -> i8;
+
+    pub fn FixDataDictionary_getFieldName(obj: FixDataDictionary_t, tag: i32) -> NullableCStr;
+
+    pub fn FixDataDictionary_getFieldTag(obj: FixDataDictionary_t, name: *const ffi::c_char)
+        -> i32;
+
+    pub fn FixDataDictionary_getFieldType(obj: FixDataDictionary_t, tag: i32) -> i32;
+
+    pub fn FixFieldType_fromName(name: *const ffi::c_char) -> i32;
+
+    pub fn FixDataDictionary_getValueName(
+        obj: FixDataDictionary_t,
+        tag: i32,
+        value: *const ffi::c_char,
+    ) -> NullableCStr;
+
+    #[must_use]
+    pub fn FixDataDictionary_hasFieldValues(obj: FixDataDictionary_t, tag: i32) -> i8;
+
+    #[must_use]
+    pub fn FixDataDictionary_isFieldValue(
+        obj: FixDataDictionary_t,
+        tag: i32,
+        value: *const ffi::c_char,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixDataDictionary_readFieldValues(
+        obj: FixDataDictionary_t,
+        tag: i32,
+        ctx: *mut ffi::c_void,
+        read: FixWriteBytesCallback_t,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixDataDictionary_isRequiredField(
+        obj: FixDataDictionary_t,
+        msgType: *const ffi::c_char,
+        tag: i32,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixDataDictionary_readRequiredFields(
+        obj: FixDataDictionary_t,
+        msgType: *const ffi::c_char,
+        ctx: *mut ffi::c_void,
+        read: FixReadIntCallback_t,
+    ) -> i8;
+
+    pub fn FixDataDictionary_getGroupDelimiter(
+        obj: FixDataDictionary_t,
+        msgType: *const ffi::c_char,
+        tag: i32,
+    ) -> i32;
+
+    #[must_use]
+    pub fn FixDataDictionary_readGroupFields(
+        obj: FixDataDictionary_t,
+        msgType: *const ffi::c_char,
+        tag: i32,
+        ctx: *mut ffi::c_void,
+        read: FixReadIntCallback_t,
+    ) -> i8;
+
     // Message store factory
 
     pub fn FixFileMessageStoreFactory_new(
@@ -240,6 +331,10 @@ extern "C" {
 
     pub fn FixApplication_delete(obj: FixApplication_t);
 
+    pub fn FixApplication_setRejectLogonText(text: *const ffi::c_char);
+
+    pub fn FixApplication_skipAdminMessage();
+
     // Socket acceptor
 
     pub fn FixAcceptor_new(
@@ -274,6 +369,35 @@ extern "C" {
         sessionId: FixSessionID_t,
     ) -> Option<FixSession_t>;
 
+    pub fn FixAcceptor_getSessionSettings(
+        obj: FixAcceptor_t,
+        session_id: FixSessionID_t,
+    ) -> Option<FixDictionary_t>;
+
+    #[must_use]
+    pub fn FixAcceptor_readSessions(
+        obj: FixAcceptor_t,
+        ctx: *mut ffi::c_void,
+        read: FixReadSessionIDCallback_t,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixAcceptor_addSession(
+        obj: FixAcceptor_t,
+        sessionId: FixSessionID_t,
+        settings: FixDictionary_t,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixAcceptor_removeSession(obj: FixAcceptor_t, sessionId: FixSessionID_t) -> i8;
+
+    #[must_use]
+    pub fn FixAcceptor_addTemplate(
+        obj: FixAcceptor_t,
+        pattern: FixSessionID_t,
+        settings: FixDictionary_t,
+    ) -> i8;
+
     pub fn FixAcceptor_delete(obj: FixAcceptor_t);
 
     // Socket initiator
@@ -310,6 +434,28 @@ extern "C" {
         sessionId: FixSessionID_t,
     ) -> Option<FixSession_t>;
 
+    pub fn FixInitiator_getSessionSettings(
+        obj: FixInitiator_t,
+        session_id: FixSessionID_t,
+    ) -> Option<FixDictionary_t>;
+
+    #[must_use]
+    pub fn FixInitiator_readSessions(
+        obj: FixInitiator_t,
+        ctx: *mut ffi::c_void,
+        read: FixReadSessionIDCallback_t,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixInitiator_addSession(
+        obj: FixInitiator_t,
+        sessionId: FixSessionID_t,
+        settings: FixDictionary_t,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixInitiator_removeSession(obj: FixInitiator_t, sessionId: FixSessionID_t) -> i8;
+
     pub fn FixInitiator_delete(obj: FixInitiator_t);
 
     // Session ID
@@ -345,14 +491,33 @@ extern "C" {
 
     pub fn FixMessage_fromString(text: *const ffi::c_char) -> Option<FixMessage_t>;
 
+    pub fn FixMessage_fromBytes(data: *const ffi::c_char, len: u64) -> Option<FixMessage_t>;
+
     pub fn FixMessage_fromStringAndDictionary(
         text: *const ffi::c_char,
         dictionary: FixDataDictionary_t,
     ) -> Option<FixMessage_t>;
 
+    pub fn FixMessage_fromStringAndDictionaries(
+        text: *const ffi::c_char,
+        sessionDictionary: FixDataDictionary_t,
+        applicationDictionary: FixDataDictionary_t,
+    ) -> Option<FixMessage_t>;
+
     #[must_use]
     pub fn FixMessage_setField(obj: FixMessage_t, tag: i32, value: *const ffi::c_char) -> i8;
 
+    #[must_use]
+    pub fn FixMessage_getFieldBytes(obj: FixMessage_t, tag: i32, len: *mut u64) -> NullableCStr;
+
+    #[must_use]
+    pub fn FixMessage_setFieldBytes(
+        obj: FixMessage_t,
+        tag: i32,
+        value: *const ffi::c_char,
+        len: u64,
+    ) -> i8;
+
     #[must_use]
     pub fn FixMessage_getField(obj: FixMessage_t, tag: i32) -> NullableCStr;
 
@@ -362,6 +527,17 @@ extern "C" {
     #[must_use]
     pub fn FixMessage_addGroup(obj: FixMessage_t, group: FixGroup_t) -> i8;
 
+    #[must_use]
+    pub fn FixMessage_removeGroup(obj: FixMessage_t, num: i32, tag: i32) -> i8;
+
+    #[must_use]
+    pub fn FixMessage_replaceGroup(obj: FixMessage_t, num: i32, group: FixGroup_t) -> i8;
+
+    #[must_use]
+    pub fn FixMessage_removeAllGroups(obj: FixMessage_t, tag: i32) -> i8;
+
+    pub fn FixMessage_getGroupCount(obj: FixMessage_t, tag: i32) -> i64;
+
     pub fn FixMessage_getStringLen(obj: FixMessage_t) -> i64;
 
     #[must_use]
@@ -371,6 +547,22 @@ extern "C" {
         buffer_len: u64,
     ) -> i8;
 
+    #[must_use]
+    pub fn FixMessage_writeBytes(
+        obj: FixMessage_t,
+        ctx: *mut ffi::c_void,
+        write: FixWriteBytesCallback_t,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixMessage_isAdmin(obj: FixMessage_t) -> i8;
+
+    #[must_use]
+    pub fn FixMessage_isApp(obj: FixMessage_t) -> i8;
+
+    #[must_use]
+    pub fn FixMessage_reverseRoute(obj: FixMessage_t, header: FixHeader_t) -> i8;
+
     pub fn FixMessage_delete(obj: FixMessage_t);
 
     // Header
@@ -388,12 +580,41 @@ extern "C" {
     #[must_use]
     pub fn FixHeader_setField(obj: FixHeader_t, tag: i32, value: *const ffi::c_char) -> i8;
 
+    #[must_use]
+    pub fn FixHeader_getFieldBytes(obj: FixHeader_t, tag: i32, len: *mut u64) -> NullableCStr;
+
+    #[must_use]
+    pub fn FixHeader_setFieldBytes(
+        obj: FixHeader_t,
+        tag: i32,
+        value: *const ffi::c_char,
+        len: u64,
+    ) -> i8;
+
     #[must_use]
     pub fn FixHeader_removeField(obj: FixHeader_t, tag: i32) -> i8;
 
     #[must_use]
     pub fn FixHeader_addGroup(obj: FixHeader_t, group: FixGroup_t) -> i8;
 
+    #[must_use]
+    pub fn FixHeader_removeGroup(obj: FixHeader_t, num: i32, tag: i32) -> i8;
+
+    #[must_use]
+    pub fn FixHeader_replaceGroup(obj: FixHeader_t, num: i32, group: FixGroup_t) -> i8;
+
+    #[must_use]
+    pub fn FixHeader_removeAllGroups(obj: FixHeader_t, tag: i32) -> i8;
+
+    pub fn FixHeader_getGroupCount(obj: FixHeader_t, tag: i32) -> i64;
+
+    #[must_use]
+    pub fn FixHeader_writeBytes(
+        obj: FixHeader_t,
+        ctx: *mut ffi::c_void,
+        write: FixWriteBytesCallback_t,
+    ) -> i8;
+
     pub fn FixHeader_delete(obj: FixHeader_t);
 
     // Trailer
@@ -411,12 +632,41 @@ extern "C" {
     #[must_use]
     pub fn FixTrailer_setField(obj: FixTrailer_t, tag: i32, value: *const ffi::c_char) -> i8;
 
+    #[must_use]
+    pub fn FixTrailer_getFieldBytes(obj: FixTrailer_t, tag: i32, len: *mut u64) -> NullableCStr;
+
+    #[must_use]
+    pub fn FixTrailer_setFieldBytes(
+        obj: FixTrailer_t,
+        tag: i32,
+        value: *const ffi::c_char,
+        len: u64,
+    ) -> i8;
+
     #[must_use]
     pub fn FixTrailer_removeField(obj: FixTrailer_t, tag: i32) -> i8;
 
     #[must_use]
     pub fn FixTrailer_addGroup(obj: FixTrailer_t, group: FixGroup_t) -> i8;
 
+    #[must_use]
+    pub fn FixTrailer_removeGroup(obj: FixTrailer_t, num: i32, tag: i32) -> i8;
+
+    #[must_use]
+    pub fn FixTrailer_replaceGroup(obj: FixTrailer_t, num: i32, group: FixGroup_t) -> i8;
+
+    #[must_use]
+    pub fn FixTrailer_removeAllGroups(obj: FixTrailer_t, tag: i32) -> i8;
+
+    pub fn FixTrailer_getGroupCount(obj: FixTrailer_t, tag: i32) -> i64;
+
+    #[must_use]
+    pub fn FixTrailer_writeBytes(
+        obj: FixTrailer_t,
+        ctx: *mut ffi::c_void,
+        write: FixWriteBytesCallback_t,
+    ) -> i8;
+
     pub fn FixTrailer_delete(obj: FixTrailer_t);
 
     // Group
@@ -444,14 +694,60 @@ extern "C" {
     #[must_use]
     pub fn FixGroup_setField(obj: FixGroup_t, tag: i32, value: *const ffi::c_char) -> i8;
 
+    #[must_use]
+    pub fn FixGroup_getFieldBytes(obj: FixGroup_t, tag: i32, len: *mut u64) -> NullableCStr;
+
+    #[must_use]
+    pub fn FixGroup_setFieldBytes(
+        obj: FixGroup_t,
+        tag: i32,
+        value: *const ffi::c_char,
+        len: u64,
+    ) -> i8;
+
     #[must_use]
     pub fn FixGroup_removeField(obj: FixGroup_t, tag: i32) -> i8;
 
     #[must_use]
     pub fn FixGroup_addGroup(obj: FixGroup_t, group: FixGroup_t) -> i8;
 
+    #[must_use]
+    pub fn FixGroup_removeGroup(obj: FixGroup_t, num: i32, tag: i32) -> i8;
+
+    #[must_use]
+    pub fn FixGroup_replaceGroup(obj: FixGroup_t, num: i32, group: FixGroup_t) -> i8;
+
+    #[must_use]
+    pub fn FixGroup_removeAllGroups(obj: FixGroup_t, tag: i32) -> i8;
+
+    pub fn FixGroup_getGroupCount(obj: FixGroup_t, tag: i32) -> i64;
+
     pub fn FixGroup_delete(obj: FixGroup_t);
 
+    // Field iterator
+
+    pub fn FixMessage_getFieldIterator(obj: FixMessage_t) -> Option<FixFieldIterator_t>;
+
+    pub fn FixHeader_getFieldIterator(obj: FixHeader_t) -> Option<FixFieldIterator_t>;
+
+    pub fn FixTrailer_getFieldIterator(obj: FixTrailer_t) -> Option<FixFieldIterator_t>;
+
+    pub fn FixGroup_getFieldIterator(obj: FixGroup_t) -> Option<FixFieldIterator_t>;
+
+    #[must_use]
+    pub fn FixFieldIterator_next(obj: FixFieldIterator_t) -> i8;
+
+    pub fn FixFieldIterator_getTag(obj: FixFieldIterator_t) -> i32;
+
+    pub fn FixFieldIterator_getValue(obj: FixFieldIterator_t, len: *mut u64) -> NullableCStr;
+
+    pub fn FixFieldIterator_getDepth(obj: FixFieldIterator_t) -> i32;
+
+    #[must_use]
+    pub fn FixFieldIterator_isGroupStart(obj: FixFieldIterator_t) -> i8;
+
+    pub fn FixFieldIterator_delete(obj: FixFieldIterator_t);
+
     // Session
 
     pub fn FixSession_sendToTarget(msg: FixMessage_t, session_id: FixSessionID_t) -> i8;
@@ -463,7 +759,41 @@ extern "C" {
     #[must_use]
     pub fn FixSession_send(session: FixSession_t, msg: FixMessage_t) -> i8;
     #[must_use]
+    pub fn FixSession_sendResendRequest(
+        session: FixSession_t,
+        beginSeqNo: u64,
+        endSeqNo: u64,
+    ) -> i8;
+    #[must_use]
+    pub fn FixSession_sendSequenceReset(session: FixSession_t, newSeqNo: u64, gapFill: i8) -> i8;
+    #[must_use]
+    pub fn FixSession_sendTestRequest(session: FixSession_t, testReqId: *const ffi::c_char) -> i8;
+    #[must_use]
     pub fn FixSession_reset(session: FixSession_t) -> i8;
     #[must_use]
     pub fn FixSession_logon(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_getExpectedSenderNum(session: FixSession_t, num: *mut u64) -> i8;
+    #[must_use]
+    pub fn FixSession_getExpectedTargetNum(session: FixSession_t, num: *mut u64) -> i8;
+    #[must_use]
+    pub fn FixSession_setNextSenderMsgSeqNum(session: FixSession_t, num: u64) -> i8;
+    #[must_use]
+    pub fn FixSession_setNextTargetMsgSeqNum(session: FixSession_t, num: u64) -> i8;
+    #[must_use]
+    pub fn FixSession_refresh(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_isEnabled(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_sentLogon(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_receivedLogon(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_isInitiator(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_isSessionTime(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_getHeartBtInt(session: FixSession_t, value: *mut i32) -> i8;
+    pub fn FixSession_getSessionID(session: FixSession_t) -> Option<FixSessionID_t>;
+    pub fn FixSession_getCreationTime(session: FixSession_t) -> NullableCStr;
 }
@@ -43,6 +43,18 @@ impl FixSpec {
     }
 }
 
+impl FixSpec {
+    /// Find field spec from its tag number.
+    pub fn field_spec(&self, number: u32) -> Option<&FieldSpec> {
+        self.field_specs.iter().find(|x| x.number == number)
+    }
+
+    /// Find field spec from its name.
+    pub fn field_spec_by_name(&self, name: &str) -> Option<&FieldSpec> {
+        self.field_specs.iter().find(|x| x.name == name)
+    }
+}
+
 impl XmlObject for FixSpec {
     const TAG_NAME: &'static str = "fix";
 }
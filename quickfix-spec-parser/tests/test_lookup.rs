@@ -0,0 +1,23 @@
+use quickfix_spec_parser::*;
+
+fn fix44() -> FixSpec {
+    parse_spec(include_bytes!(
+        "../../quickfix-ffi/libquickfix/spec/FIX44.xml"
+    ))
+    .unwrap()
+}
+
+#[test]
+fn test_field_lookup() {
+    let spec = fix44();
+
+    let side = spec.field_spec(54).unwrap();
+    assert_eq!(side.name, "Side");
+    assert!(side
+        .values
+        .iter()
+        .any(|x| x.value == "1" && x.description == "BUY"));
+    assert_eq!(spec.field_spec_by_name("Side").unwrap().number, 54);
+    assert!(spec.field_spec(99999).is_none());
+    assert!(spec.field_spec_by_name("NotAField").is_none());
+}
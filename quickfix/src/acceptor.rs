@@ -1,14 +1,15 @@
 use std::marker::PhantomData;
 
 use quickfix_ffi::{
-    FixAcceptor_block, FixAcceptor_delete, FixAcceptor_getSession, FixAcceptor_isLoggedOn,
-    FixAcceptor_isStopped, FixAcceptor_new, FixAcceptor_poll, FixAcceptor_start, FixAcceptor_stop,
-    FixAcceptor_t,
+    FixAcceptor_addSession, FixAcceptor_addTemplate, FixAcceptor_block, FixAcceptor_delete,
+    FixAcceptor_getSession, FixAcceptor_getSessionSettings, FixAcceptor_isLoggedOn,
+    FixAcceptor_isStopped, FixAcceptor_new, FixAcceptor_poll, FixAcceptor_readSessions,
+    FixAcceptor_removeSession, FixAcceptor_start, FixAcceptor_stop, FixAcceptor_t,
 };
 
 use crate::{
-    utils::{ffi_code_to_bool, ffi_code_to_result},
-    Application, ApplicationCallback, ConnectionHandler, FfiMessageStoreFactory,
+    utils::{ffi_code_to_bool, ffi_code_to_result, push_session_id_to_vec},
+    Application, ApplicationCallback, ConnectionHandler, Dictionary, FfiMessageStoreFactory,
     FixSocketServerKind, LogCallback, LogFactory, QuickFixError, Session, SessionContainer,
     SessionId, SessionSettings,
 };
@@ -40,6 +41,31 @@ where
         store_factory: &'a S,
         log_factory: &'a LogFactory<L>,
         server_mode: FixSocketServerKind,
+    ) -> Result<Self, QuickFixError> {
+        // SAFETY: components are borrowed for the acceptor lifetime.
+        unsafe {
+            Self::try_new_unchecked(
+                settings,
+                application,
+                store_factory,
+                log_factory,
+                server_mode,
+            )
+        }
+    }
+
+    /// Create new struct without binding its lifetime to its components.
+    ///
+    /// # Safety
+    ///
+    /// C++ objects behind `application`, `store_factory` and `log_factory` must outlive
+    /// the returned value.
+    pub(crate) unsafe fn try_new_unchecked(
+        settings: &SessionSettings,
+        application: &Application<A>,
+        store_factory: &S,
+        log_factory: &LogFactory<L>,
+        server_mode: FixSocketServerKind,
     ) -> Result<Self, QuickFixError> {
         match unsafe {
             FixAcceptor_new(
@@ -60,6 +86,68 @@ where
             None => Err(QuickFixError::from_last_error()),
         }
     }
+
+    /// List IDs of all sessions handled by this acceptor.
+    pub fn session_ids(&self) -> Result<Vec<SessionId>, QuickFixError> {
+        let mut session_ids = Vec::new();
+        ffi_code_to_result(unsafe {
+            FixAcceptor_readSessions(
+                self.inner,
+                (&mut session_ids as *mut Vec<SessionId>).cast(),
+                push_session_id_to_vec,
+            )
+        })?;
+        Ok(session_ids)
+    }
+
+    /// Add a new session while acceptor is running.
+    ///
+    /// Settings are merged with the global ones and the session uses the acceptor message store
+    /// and log factories. Other sessions are not affected.
+    ///
+    /// The session must listen on a `SocketAcceptPort` already opened by the running acceptor.
+    /// Ports of sessions added to a stopped acceptor are opened when it starts.
+    pub fn add_session(
+        &mut self,
+        session_id: SessionId,
+        settings: Dictionary,
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixAcceptor_addSession(self.inner, session_id.0, settings.0) })
+    }
+
+    /// Logout and remove a session while acceptor is running.
+    ///
+    /// Session cannot be looked up anymore and its ID can be added again right away. It is released
+    /// once its connection is closed.
+    pub fn remove_session(&mut self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixAcceptor_removeSession(self.inner, session_id.0) })
+    }
+
+    /// Create sessions on demand for unknown counterparties.
+    ///
+    /// When a logon is received for a session that does not exist, its ID is matched against
+    /// registered patterns, in registration order. Pattern `BeginString`, `SenderCompID` and
+    /// `TargetCompID` either match exactly, or match any value starting with the text before
+    /// a trailing `*`. The pattern qualifier is ignored.
+    ///
+    /// A session is then created with the template settings, once approved by
+    /// [`ApplicationCallback::on_dynamic_session`], and can be looked up as any other session.
+    /// The logon is accepted when the template `SocketAcceptPort` is the port it was received on.
+    pub fn add_template(
+        &mut self,
+        pattern: SessionId,
+        settings: Dictionary,
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixAcceptor_addTemplate(self.inner, pattern.0, settings.0) })
+    }
+
+    /// Borrow all sessions handled by this acceptor.
+    pub fn sessions(&self) -> Result<Vec<Session<'_>>, QuickFixError> {
+        self.session_ids()?
+            .into_iter()
+            .map(|session_id| self.session(session_id))
+            .collect()
+    }
 }
 
 impl<A, L, S> ConnectionHandler for Acceptor<'_, A, L, S>
@@ -111,6 +199,14 @@ where
                 })
         }
     }
+
+    fn session_settings(&self, session_id: &SessionId) -> Result<Dictionary, QuickFixError> {
+        unsafe { FixAcceptor_getSessionSettings(self.inner, session_id.0) }
+            .map(Dictionary)
+            .ok_or_else(|| {
+                QuickFixError::SessionNotFound(format!("No session found: {session_id:?}"))
+            })
+    }
 }
 
 impl<A, L, S> Drop for Acceptor<'_, A, L, S>
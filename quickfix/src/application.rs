@@ -1,11 +1,44 @@
-use std::{any::Any, ffi, marker::PhantomData, mem::ManuallyDrop, panic::catch_unwind};
+use std::{
+    ffi::{self, CString},
+    fmt,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ptr::NonNull,
+    sync::Arc,
+};
 
 use quickfix_ffi::{
-    FixApplicationCallbacks_t, FixApplication_delete, FixApplication_new, FixApplication_t,
+    FixApplicationCallbacks_t, FixApplication_delete, FixApplication_new,
+    FixApplication_setRejectLogonText, FixApplication_skipAdminMessage, FixApplication_t,
     FixMessage_t, FixSessionID_t,
 };
 
-use crate::{Message, QuickFixError, SessionId};
+use crate::{
+    panic_policy::CallbackContext, CallbackPanic, FieldMap, FromFixValue, Message, PanicPolicy,
+    QuickFixError, SessionId, UtcTimestamp,
+};
+
+const TAG_POSS_DUP_FLAG: i32 = 43;
+const TAG_ORIG_SENDING_TIME: i32 = 122;
+const TAG_USERNAME: i32 = 553;
+const TAG_PASSWORD: i32 = 554;
+const MSG_TYPE_LOGON: &str = "A";
+
+/// Admin messages that can be skipped: session state does not depend on them being sent.
+const SKIPPABLE_ADMIN_MSG_TYPES: [&str; 3] = [
+    "0", // Heartbeat
+    "1", // TestRequest
+    "3", // Reject
+];
+
+/// Error result that can occurs from a `on_admin_outgoing` callback.
+#[derive(Debug)]
+pub enum MsgToAdminError {
+    /// Indicates user does not want to send a message.
+    ///
+    /// Message is dropped before getting a sequence number.
+    DoNotSend,
+}
 
 /// Error result that can occurs from a `on_msg_to_app` callback.
 #[derive(Debug)]
@@ -14,6 +47,15 @@ pub enum MsgToAppError {
     DoNotSend,
 }
 
+/// Error result that can occurs from a `on_logon_request` callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogonRequestError {
+    /// Reject logon, and disconnect counterparty.
+    ///
+    /// Reason is sent in the `Text` (58) field of the `Logout` answer.
+    RejectLogon(String),
+}
+
 /// Error result that can occurs from a `on_msg_from_admin` callback.
 #[derive(Debug)]
 pub enum MsgFromAdminError {
@@ -28,7 +70,7 @@ pub enum MsgFromAdminError {
 }
 
 /// Error result that can occurs from a `on_msg_from_app` callback.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MsgFromAppError {
     /// Field not found inside a message.
     FieldNotFound,
@@ -40,15 +82,95 @@ pub enum MsgFromAppError {
     UnsupportedMessageType,
 }
 
+/// `Username` (553) and `Password` (554) of a `Logon` message.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct LogonCredentials {
+    /// `Username` (553) value.
+    pub username: Option<String>,
+    /// `Password` (554) value.
+    pub password: Option<String>,
+}
+
+impl LogonCredentials {
+    /// Create new struct with both values set.
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: Some(username.into()),
+            password: Some(password.into()),
+        }
+    }
+
+    fn from_message(msg: &Message) -> Self {
+        Self {
+            username: msg.get_field(TAG_USERNAME),
+            password: msg.get_field(TAG_PASSWORD),
+        }
+    }
+
+    fn write_to(&self, msg: &mut Message) -> Result<(), QuickFixError> {
+        for (tag, value) in [
+            (TAG_USERNAME, &self.username),
+            (TAG_PASSWORD, &self.password),
+        ] {
+            match value {
+                // NUL bytes cannot be sent anyway.
+                Some(value) => msg.set_field(tag, value.replace('\0', ""))?,
+                None => msg.remove_field(tag)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for LogonCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogonCredentials")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+/// What to do with a stored application message, when counterparty asks to resend it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResendAction {
+    /// Resend message, as-is or with the changes made to it in `on_resend` callback.
+    Resend,
+    /// Do not resend message, its sequence number is skipped with a `SequenceReset-GapFill`.
+    GapFill,
+}
+
+/// Header values of a message being resent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResendContext {
+    /// `PossDupFlag` (43) value, which tells counterparty message may have already been received.
+    pub poss_dup_flag: bool,
+    /// `OrigSendingTime` (122) value, which is the time message has originally been sent.
+    pub orig_sending_time: Option<UtcTimestamp>,
+}
+
+impl ResendContext {
+    fn from_message(msg: &Message) -> Self {
+        msg.with_header(|header| Self {
+            poss_dup_flag: header
+                .get_field(TAG_POSS_DUP_FLAG)
+                .and_then(|value| bool::from_fix_value(&value))
+                .unwrap_or_default(),
+            orig_sending_time: header
+                .get_field(TAG_ORIG_SENDING_TIME)
+                .and_then(|value| UtcTimestamp::from_fix_value(&value)),
+        })
+    }
+}
+
 trait AsFixCallbackCode {
     fn as_callback_code(&self) -> i8;
 }
 
-fn callback_to_code<T: AsFixCallbackCode>(input: Result<Result<(), T>, Box<dyn Any + Send>>) -> i8 {
+fn callback_to_code<T: AsFixCallbackCode>(input: Result<(), T>) -> i8 {
     match input {
-        Err(_) => 0, // Just ignore the panic from rust and let FIX engine continue its workflow.
-        Ok(Ok(())) => 0, // Everything goes right 🎇!
-        Ok(Err(x)) => x.as_callback_code(), // Use as deliberately change the control flow.
+        Ok(()) => 0,                    // Everything goes right 🎇!
+        Err(x) => x.as_callback_code(), // Use as deliberately change the control flow.
     }
 }
 
@@ -94,11 +216,38 @@ pub trait ApplicationCallback {
     /// On session logout.
     fn on_logout(&self, session: &SessionId) {}
 
+    /// Called before sending a `Logon`, and before [`Self::on_msg_to_admin`].
+    ///
+    /// Credentials are read from the message, and written back to it once updated.
+    fn on_logon_outgoing(&self, credentials: &mut LogonCredentials, session: &SessionId) {}
+
+    /// Called when a `Logon` is received, before [`Self::on_msg_from_admin`].
+    ///
+    /// This is the counterparty logon request on acceptor side, and its answer on initiator side.
+    fn on_logon_request(
+        &self,
+        credentials: &LogonCredentials,
+        session: &SessionId,
+    ) -> Result<(), LogonRequestError> {
+        Ok(())
+    }
+
     /// Called before sending message to admin level.
     ///
     /// Message can be updated at this stage.
     fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {}
 
+    /// Called before sending a `Heartbeat`, `TestRequest` or `Reject`, after
+    /// [`Self::on_msg_to_admin`].
+    ///
+    /// Message is not sent when [`MsgToAdminError::DoNotSend`] is returned, and quickfix
+    /// records a session event with text `Admin message not sent by application`.
+    /// Other admin messages cannot be skipped: session would still wait for the answer to a
+    /// skipped `Logon`, `Logout` or `ResendRequest` until it times out.
+    fn on_admin_outgoing(&self, msg: &Message, session: &SessionId) -> Result<(), MsgToAdminError> {
+        Ok(())
+    }
+
     /// Called before sending message to application level.
     ///
     /// Message can be updated at this stage.
@@ -106,6 +255,23 @@ pub trait ApplicationCallback {
         Ok(())
     }
 
+    /// Called before resending a stored message from application level, when counterparty sent
+    /// a `ResendRequest`.
+    ///
+    /// It is only called from the session resend path: messages sent with `PossDupFlag` by the
+    /// application itself are not resent messages.
+    ///
+    /// Message can be updated at this stage. [`Self::on_msg_to_app`] is then called on resent
+    /// messages, as on any other application message.
+    fn on_resend(
+        &self,
+        msg: &mut Message,
+        context: &ResendContext,
+        session: &SessionId,
+    ) -> ResendAction {
+        ResendAction::Resend
+    }
+
     /// Called after received a message from admin level.
     fn on_msg_from_admin(
         &self,
@@ -119,11 +285,23 @@ pub trait ApplicationCallback {
     fn on_msg_from_app(&self, msg: &Message, session: &SessionId) -> Result<(), MsgFromAppError> {
         Ok(())
     }
+
+    /// Called before creating a session from an acceptor template.
+    ///
+    /// Return `false` to reject the counterparty.
+    fn on_dynamic_session(&self, session: &SessionId) -> bool {
+        true
+    }
 }
 
 /// Application callback wrapper.
 #[derive(Debug)]
-pub struct Application<'a, C: ApplicationCallback>(pub(crate) FixApplication_t, PhantomData<&'a C>);
+pub struct Application<'a, C: ApplicationCallback>(
+    pub(crate) FixApplication_t,
+    PhantomData<&'a C>,
+    /// Callbacks and panic policy, given back by C++ code to each callback.
+    NonNull<CallbackContext<C>>,
+);
 
 impl<'a, C> Application<'a, C>
 where
@@ -131,17 +309,48 @@ where
 {
     /// Try create new struct from its underlying components.
     pub fn try_new(callbacks: &'a C) -> Result<Self, QuickFixError> {
+        Self::try_from_context(CallbackContext::new_borrowed(callbacks))
+    }
+
+    /// Try create new struct owning its callbacks.
+    ///
+    /// Callbacks can still be shared with other code. Use `Arc::from` to build it from a `Box<C>`.
+    pub fn try_new_owned(callbacks: Arc<C>) -> Result<Application<'static, C>, QuickFixError> {
+        Application::try_from_context(CallbackContext::new_owned(callbacks))
+    }
+
+    fn try_from_context(context: NonNull<CallbackContext<C>>) -> Result<Self, QuickFixError> {
         match unsafe {
-            FixApplication_new(
-                callbacks as *const C as *const ffi::c_void,
-                &Self::CALLBACKS,
-            )
+            FixApplication_new(context.as_ptr() as *const ffi::c_void, &Self::CALLBACKS)
         } {
-            Some(fix_application) => Ok(Self(fix_application, PhantomData)),
-            None => Err(QuickFixError::from_last_error()),
+            Some(fix_application) => Ok(Self(fix_application, PhantomData, context)),
+            None => {
+                unsafe { CallbackContext::release(context) };
+                Err(QuickFixError::from_last_error())
+            }
         }
     }
 
+    /// Set what to do when a callback panics.
+    ///
+    /// Default is [`PanicPolicy::Ignore`].
+    pub fn with_panic_policy(self, policy: PanicPolicy) -> Self {
+        // SAFETY: application is not used by any acceptor / initiator yet, since it is owned.
+        unsafe { CallbackContext::set_policy(self.2, policy) };
+        self
+    }
+
+    /// Set a function called with every panic caught from a callback, before applying panic
+    /// policy.
+    pub fn with_panic_hook<F>(self, hook: F) -> Self
+    where
+        F: Fn(&CallbackPanic<'_>) + Send + Sync + 'static,
+    {
+        // SAFETY: application is not used by any acceptor / initiator yet, since it is owned.
+        unsafe { CallbackContext::set_hook(self.2, Box::new(hook)) };
+        self
+    }
+
     const CALLBACKS: FixApplicationCallbacks_t = FixApplicationCallbacks_t {
         onCreate: Self::on_create,
         onLogon: Self::on_logon,
@@ -150,43 +359,70 @@ where
         toApp: Self::to_app,
         fromAdmin: Self::from_admin,
         fromApp: Self::from_app,
+        onDynamicSession: Self::on_dynamic_session,
+        onResend: Self::on_resend,
     };
 
     extern "C" fn on_create(data: *const ffi::c_void, session: FixSessionID_t) {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
         let session_id = ManuallyDrop::new(SessionId(session));
 
-        let _ = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
+        context.call("on_create", Some(&session_id), |this| {
             this.on_create(&session_id);
         });
     }
 
     extern "C" fn on_logon(data: *const ffi::c_void, session: FixSessionID_t) {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
         let session_id = ManuallyDrop::new(SessionId(session));
 
-        let _ = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
+        context.call("on_logon", Some(&session_id), |this| {
             this.on_logon(&session_id);
         });
     }
 
     extern "C" fn on_logout(data: *const ffi::c_void, session: FixSessionID_t) {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
         let session_id = ManuallyDrop::new(SessionId(session));
 
-        let _ = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
+        context.call("on_logout", Some(&session_id), |this| {
             this.on_logout(&session_id);
         });
     }
 
     extern "C" fn to_admin(data: *const ffi::c_void, msg: FixMessage_t, session: FixSessionID_t) {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
+        let mut msg = ManuallyDrop::new(Message(msg));
         let session_id = ManuallyDrop::new(SessionId(session));
+        let msg_type = msg.msg_type();
+
+        if msg_type.as_deref() == Some(MSG_TYPE_LOGON) {
+            context.call("on_logon_outgoing", Some(&session_id), |this| {
+                let mut credentials = LogonCredentials::from_message(&msg);
+                this.on_logon_outgoing(&mut credentials, &session_id);
+                // NUL bytes are stripped: fields are only left unchanged if quickfix fails.
+                let _ = credentials.write_to(&mut msg);
+            });
+        }
 
-        let _ = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
-            let mut msg = ManuallyDrop::new(Message(msg));
+        context.call("on_msg_to_admin", Some(&session_id), |this| {
             this.on_msg_to_admin(&mut msg, &session_id);
         });
+
+        if !msg_type.is_some_and(|msg_type| SKIPPABLE_ADMIN_MSG_TYPES.contains(&msg_type.as_str()))
+        {
+            return;
+        }
+        let code = match context.call("on_admin_outgoing", Some(&session_id), |this| {
+            this.on_admin_outgoing(&msg, &session_id)
+        }) {
+            Some(Ok(())) => quickfix_ffi::CALLBACK_OK,
+            Some(Err(MsgToAdminError::DoNotSend)) => quickfix_ffi::CALLBACK_RESULT_DO_NOT_SEND,
+            None => context.reject_code(quickfix_ffi::CALLBACK_RESULT_DO_NOT_SEND),
+        };
+        if code != quickfix_ffi::CALLBACK_OK {
+            unsafe { FixApplication_skipAdminMessage() };
+        }
     }
 
     extern "C" fn to_app(
@@ -194,15 +430,16 @@ where
         msg: FixMessage_t,
         session: FixSessionID_t,
     ) -> i8 {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
+        let mut msg = ManuallyDrop::new(Message(msg));
         let session_id = ManuallyDrop::new(SessionId(session));
 
-        let output_code = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
-            let mut msg = ManuallyDrop::new(Message(msg));
+        match context.call("on_msg_to_app", Some(&session_id), |this| {
             this.on_msg_to_app(&mut msg, &session_id)
-        });
-
-        callback_to_code(output_code)
+        }) {
+            Some(result) => callback_to_code(result),
+            None => context.reject_code(quickfix_ffi::CALLBACK_RESULT_DO_NOT_SEND),
+        }
     }
 
     extern "C" fn from_admin(
@@ -210,15 +447,40 @@ where
         msg: FixMessage_t,
         session: FixSessionID_t,
     ) -> i8 {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
         let msg = ManuallyDrop::new(Message(msg));
         let session_id = ManuallyDrop::new(SessionId(session));
 
-        let output_code = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
-            this.on_msg_from_admin(&msg, &session_id)
-        });
+        if msg.msg_type().as_deref() == Some(MSG_TYPE_LOGON) {
+            match context.call("on_logon_request", Some(&session_id), |this| {
+                this.on_logon_request(&LogonCredentials::from_message(&msg), &session_id)
+            }) {
+                Some(Ok(())) => {}
+                Some(Err(LogonRequestError::RejectLogon(reason))) => {
+                    // NUL bytes cannot be sent anyway.
+                    let reason = CString::new(reason.replace('\0', "")).unwrap_or_default();
+                    unsafe { FixApplication_setRejectLogonText(reason.as_ptr()) };
+                    return quickfix_ffi::CALLBACK_RESULT_REJECT_LOGON;
+                }
+                None => {
+                    let code = context.reject_code(quickfix_ffi::CALLBACK_RESULT_REJECT_LOGON);
+                    if code != quickfix_ffi::CALLBACK_OK {
+                        return code;
+                    }
+                }
+            }
+        }
 
-        callback_to_code(output_code)
+        match context.call("on_msg_from_admin", Some(&session_id), |this| {
+            this.on_msg_from_admin(&msg, &session_id)
+        }) {
+            Some(result) => callback_to_code(result),
+            None => context.reject_code(if msg.msg_type().as_deref() == Some(MSG_TYPE_LOGON) {
+                quickfix_ffi::CALLBACK_RESULT_REJECT_LOGON
+            } else {
+                quickfix_ffi::CALLBACK_RESULT_INCORRECT_TAG_VALUE
+            }),
+        }
     }
 
     extern "C" fn from_app(
@@ -226,20 +488,54 @@ where
         msg: FixMessage_t,
         session: FixSessionID_t,
     ) -> i8 {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
         let msg = ManuallyDrop::new(Message(msg));
         let session_id = ManuallyDrop::new(SessionId(session));
 
-        let output_code = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
+        match context.call("on_msg_from_app", Some(&session_id), |this| {
             this.on_msg_from_app(&msg, &session_id)
-        });
+        }) {
+            Some(result) => callback_to_code(result),
+            None => context.reject_code(quickfix_ffi::CALLBACK_RESULT_UNSUPPORTED_MESSAGE_TYPE),
+        }
+    }
 
-        callback_to_code(output_code)
+    extern "C" fn on_dynamic_session(data: *const ffi::c_void, session: FixSessionID_t) -> i8 {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
+        let session_id = ManuallyDrop::new(SessionId(session));
+
+        match context.call("on_dynamic_session", Some(&session_id), |this| {
+            this.on_dynamic_session(&session_id)
+        }) {
+            Some(true) => quickfix_ffi::CALLBACK_OK,
+            // Do not create sessions when user code panics.
+            Some(false) | None => quickfix_ffi::CALLBACK_RESULT_REJECT_LOGON,
+        }
+    }
+
+    extern "C" fn on_resend(
+        data: *const ffi::c_void,
+        msg: FixMessage_t,
+        session: FixSessionID_t,
+    ) -> i8 {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
+        let mut msg = ManuallyDrop::new(Message(msg));
+        let session_id = ManuallyDrop::new(SessionId(session));
+
+        match context.call("on_resend", Some(&session_id), |this| {
+            let resend_context = ResendContext::from_message(&msg);
+            this.on_resend(&mut msg, &resend_context, &session_id)
+        }) {
+            Some(ResendAction::GapFill) => quickfix_ffi::CALLBACK_RESULT_DO_NOT_SEND,
+            Some(ResendAction::Resend) => quickfix_ffi::CALLBACK_OK,
+            None => context.reject_code(quickfix_ffi::CALLBACK_RESULT_DO_NOT_SEND),
+        }
     }
 }
 
 impl<C: ApplicationCallback> Drop for Application<'_, C> {
     fn drop(&mut self) {
         unsafe { FixApplication_delete(self.0) };
+        unsafe { CallbackContext::release(self.2) };
     }
 }
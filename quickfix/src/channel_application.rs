@@ -0,0 +1,288 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TrySendError},
+    },
+    time::Duration,
+};
+
+use crate::{
+    ApplicationCallback, Message, MsgFromAdminError, MsgFromAppError, MsgToAppError, SessionId,
+};
+
+/// Application callback converted to a value by [`ChannelApplication`].
+///
+/// Messages are copies: updating them has no effect on what is sent or received.
+#[derive(Debug)]
+pub enum FixEvent {
+    /// Session created.
+    Create(SessionId),
+    /// Session logon.
+    Logon(SessionId),
+    /// Session logout.
+    Logout(SessionId),
+    /// Message about to be sent to admin level.
+    ToAdmin(Message, SessionId),
+    /// Message about to be sent to application level.
+    ToApp(Message, SessionId),
+    /// Message received from admin level.
+    FromAdmin(Message, SessionId),
+    /// Message received from application level.
+    FromApp(Message, SessionId, FromAppReply),
+}
+
+impl FixEvent {
+    /// Get ID of the session the event comes from.
+    pub fn session_id(&self) -> &SessionId {
+        match self {
+            Self::Create(session_id)
+            | Self::Logon(session_id)
+            | Self::Logout(session_id)
+            | Self::ToAdmin(_, session_id)
+            | Self::ToApp(_, session_id)
+            | Self::FromAdmin(_, session_id)
+            | Self::FromApp(_, session_id, _) => session_id,
+        }
+    }
+}
+
+/// Answer to a [`FixEvent::FromApp`] event.
+///
+/// Answer is only awaited when [`ChannelApplication::with_reply_timeout`] is set. Dropping it
+/// without replying accepts the message, whatever the [`ReplyTimeoutPolicy`].
+#[derive(Debug)]
+pub struct FromAppReply(Option<SyncSender<Result<(), MsgFromAppError>>>);
+
+impl FromAppReply {
+    /// Check if application callback is waiting for this answer.
+    pub fn is_awaited(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Accept or reject received message.
+    pub fn send(self, result: Result<(), MsgFromAppError>) {
+        if let Some(sender) = self.0 {
+            // Callback may have timed out already, there is no one left to answer.
+            let _ = sender.try_send(result);
+        }
+    }
+}
+
+/// What to do with an event when channel is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackpressurePolicy {
+    /// Wait for the receiver to make room, blocking the quickfix thread calling back.
+    #[default]
+    Block,
+    /// Drop the event.
+    DropEvent,
+}
+
+/// What to do with a received application message when no answer comes in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReplyTimeoutPolicy {
+    /// Accept the message.
+    #[default]
+    Accept,
+    /// Reject the message with the given error.
+    Reject(MsgFromAppError),
+}
+
+impl ReplyTimeoutPolicy {
+    fn result(self) -> Result<(), MsgFromAppError> {
+        match self {
+            Self::Accept => Ok(()),
+            Self::Reject(error) => Err(error),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum EventSender {
+    Std(SyncSender<FixEvent>),
+    #[cfg(feature = "crossbeam-channel")]
+    Crossbeam(crossbeam_channel::Sender<FixEvent>),
+    #[cfg(feature = "tokio")]
+    Tokio(tokio::sync::mpsc::Sender<FixEvent>),
+}
+
+impl EventSender {
+    fn send(&self, event: FixEvent, policy: BackpressurePolicy) -> bool {
+        match (self, policy) {
+            (Self::Std(sender), BackpressurePolicy::Block) => sender.send(event).is_ok(),
+            (Self::Std(sender), BackpressurePolicy::DropEvent) => match sender.try_send(event) {
+                Ok(()) => true,
+                Err(TrySendError::Full(_) | TrySendError::Disconnected(_)) => false,
+            },
+            #[cfg(feature = "crossbeam-channel")]
+            (Self::Crossbeam(sender), BackpressurePolicy::Block) => sender.send(event).is_ok(),
+            #[cfg(feature = "crossbeam-channel")]
+            (Self::Crossbeam(sender), BackpressurePolicy::DropEvent) => {
+                sender.try_send(event).is_ok()
+            }
+            #[cfg(feature = "tokio")]
+            (Self::Tokio(sender), BackpressurePolicy::Block) => {
+                crate::tokio_support::blocking_send(sender, event)
+            }
+            #[cfg(feature = "tokio")]
+            (Self::Tokio(sender), BackpressurePolicy::DropEvent) => sender.try_send(event).is_ok(),
+        }
+    }
+}
+
+/// Application callbacks sending every event to a channel.
+///
+/// Events are consumed from the receiving side of the channel, on any thread, instead of
+/// implementing [`ApplicationCallback`]. By default, received application messages are accepted
+/// as soon as their event is sent: see [`ChannelApplication::with_reply_timeout`] to reject them.
+#[derive(Debug)]
+pub struct ChannelApplication {
+    sender: EventSender,
+    backpressure: BackpressurePolicy,
+    reply_timeout: Option<Duration>,
+    reply_timeout_policy: ReplyTimeoutPolicy,
+    dropped_count: AtomicU64,
+}
+
+impl ChannelApplication {
+    /// Create new struct and the bounded channel receiving its events.
+    pub fn new(capacity: usize) -> (Self, Receiver<FixEvent>) {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        (Self::with_sender(sender), receiver)
+    }
+
+    /// Create new struct sending events to an existing channel.
+    pub fn with_sender(sender: SyncSender<FixEvent>) -> Self {
+        Self::with_event_sender(EventSender::Std(sender))
+    }
+
+    /// Create new struct sending events to a crossbeam channel.
+    #[cfg(feature = "crossbeam-channel")]
+    pub fn with_crossbeam_sender(sender: crossbeam_channel::Sender<FixEvent>) -> Self {
+        Self::with_event_sender(EventSender::Crossbeam(sender))
+    }
+
+    /// Create new struct and the bounded async stream receiving its events.
+    ///
+    /// With [`BackpressurePolicy::Block`], callbacks block the thread they are called from until
+    /// there is room in the stream. Callbacks run by a current thread runtime (like when
+    /// calling [`crate::ConnectionHandler::start`] from async code) cannot block: their events
+    /// are dropped when stream is full.
+    #[cfg(feature = "tokio")]
+    pub fn new_async(capacity: usize) -> (Self, crate::FixEventStream) {
+        let (sender, receiver) = tokio::sync::mpsc::channel(capacity);
+        (
+            Self::with_tokio_sender(sender),
+            crate::FixEventStream::new(receiver),
+        )
+    }
+
+    /// Create new struct sending events to a tokio channel.
+    #[cfg(feature = "tokio")]
+    pub fn with_tokio_sender(sender: tokio::sync::mpsc::Sender<FixEvent>) -> Self {
+        Self::with_event_sender(EventSender::Tokio(sender))
+    }
+
+    fn with_event_sender(sender: EventSender) -> Self {
+        Self {
+            sender,
+            backpressure: BackpressurePolicy::default(),
+            reply_timeout: None,
+            reply_timeout_policy: ReplyTimeoutPolicy::default(),
+            dropped_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Set what to do with events when channel is full.
+    pub fn with_backpressure(mut self, policy: BackpressurePolicy) -> Self {
+        self.backpressure = policy;
+        self
+    }
+
+    /// Wait for the answer to [`FixEvent::FromApp`] events, before returning from
+    /// [`ApplicationCallback::on_msg_from_app`].
+    ///
+    /// If no answer is received before `timeout`, or if event is dropped, message is handled
+    /// according to [`ChannelApplication::with_reply_timeout_policy`]: it is accepted by default.
+    pub fn with_reply_timeout(mut self, timeout: Duration) -> Self {
+        self.reply_timeout = Some(timeout);
+        self
+    }
+
+    /// Set what to do with received messages when their answer does not come in time.
+    pub fn with_reply_timeout_policy(mut self, policy: ReplyTimeoutPolicy) -> Self {
+        self.reply_timeout_policy = policy;
+        self
+    }
+
+    /// Get number of events dropped, because channel was full or receiver is gone.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+
+    fn emit(&self, event: FixEvent) -> bool {
+        let sent = self.sender.send(event, self.backpressure);
+        if !sent {
+            self.dropped_count.fetch_add(1, Ordering::Relaxed);
+        }
+        sent
+    }
+}
+
+impl ApplicationCallback for ChannelApplication {
+    fn on_create(&self, session: &SessionId) {
+        self.emit(FixEvent::Create(session.clone()));
+    }
+
+    fn on_logon(&self, session: &SessionId) {
+        self.emit(FixEvent::Logon(session.clone()));
+    }
+
+    fn on_logout(&self, session: &SessionId) {
+        self.emit(FixEvent::Logout(session.clone()));
+    }
+
+    fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {
+        self.emit(FixEvent::ToAdmin(msg.clone(), session.clone()));
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, session: &SessionId) -> Result<(), MsgToAppError> {
+        self.emit(FixEvent::ToApp(msg.clone(), session.clone()));
+        Ok(())
+    }
+
+    fn on_msg_from_admin(
+        &self,
+        msg: &Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.emit(FixEvent::FromAdmin(msg.clone(), session.clone()));
+        Ok(())
+    }
+
+    fn on_msg_from_app(&self, msg: &Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        let Some(timeout) = self.reply_timeout else {
+            self.emit(FixEvent::FromApp(
+                msg.clone(),
+                session.clone(),
+                FromAppReply(None),
+            ));
+            return Ok(());
+        };
+
+        let (reply_sender, reply_receiver) = mpsc::sync_channel(1);
+        let event = FixEvent::FromApp(
+            msg.clone(),
+            session.clone(),
+            FromAppReply(Some(reply_sender)),
+        );
+        if !self.emit(event) {
+            return self.reply_timeout_policy.result();
+        }
+        match reply_receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => self.reply_timeout_policy.result(),
+            Err(RecvTimeoutError::Disconnected) => Ok(()),
+        }
+    }
+}
@@ -1,11 +1,23 @@
 use std::{ffi::CString, fmt, path::Path};
 
 use quickfix_ffi::{
-    FixDataDictionary_delete, FixDataDictionary_fromPath, FixDataDictionary_new,
-    FixDataDictionary_t, FixMessage_fromStringAndDictionary,
+    FixDataDictionary_delete, FixDataDictionary_fromPath, FixDataDictionary_getFieldName,
+    FixDataDictionary_getFieldTag, FixDataDictionary_getFieldType,
+    FixDataDictionary_getGroupDelimiter, FixDataDictionary_getValueName,
+    FixDataDictionary_hasFieldValues, FixDataDictionary_isFieldValue,
+    FixDataDictionary_isRequiredField, FixDataDictionary_new, FixDataDictionary_readFieldValues,
+    FixDataDictionary_readGroupFields, FixDataDictionary_readRequiredFields, FixDataDictionary_t,
+    FixDataDictionary_validate, FixMessage_fromStringAndDictionaries,
+    FixMessage_fromStringAndDictionary,
 };
 
-use crate::{Message, QuickFixError};
+use crate::{
+    utils::{
+        ffi_code_to_bool, ffi_code_to_result, push_int_to_vec, push_string_to_vec,
+        read_checked_cstr,
+    },
+    FieldType, Message, QuickFixError, SessionRejectReason, ValidationError,
+};
 
 /// Represents a data dictionary for a version of FIX.
 pub struct DataDictionary(FixDataDictionary_t);
@@ -36,6 +48,152 @@ impl DataDictionary {
             .map(Message)
             .ok_or_else(QuickFixError::from_last_error)
     }
+
+    /// Create a new FIX messages using current dictionary for header / trailer and
+    /// `application` dictionary for message body.
+    ///
+    /// This is required for FIXT 1.1 messages, where current dictionary is the transport
+    /// one (ex: `FIXT11.xml`) and application dictionary is a FIX 5.0+ one (ex: `FIX50SP2.xml`).
+    /// Admin messages body are parsed using transport dictionary.
+    pub fn try_build_app_message(
+        &self,
+        application: &DataDictionary,
+        text: &str,
+    ) -> Result<Message, QuickFixError> {
+        let ffi_text = CString::new(text)?;
+        unsafe { FixMessage_fromStringAndDictionaries(ffi_text.as_ptr(), self.0, application.0) }
+            .map(Message)
+            .ok_or_else(QuickFixError::from_last_error)
+    }
+}
+
+impl DataDictionary {
+    /// Validate message against current dictionary.
+    ///
+    /// Checks are the same as what a session does when receiving a message
+    /// (unknown tags, required tags, values, formats, groups, ...).
+    pub fn validate(&self, msg: &Message) -> Result<(), ValidationError> {
+        let mut tag = 0;
+        ffi_code_to_result(unsafe { FixDataDictionary_validate(self.0, msg.0, &mut tag) }).map_err(
+            |reason| ValidationError {
+                tag: (tag != 0).then_some(tag),
+                session_reject_reason: SessionRejectReason::from_error(&reason),
+                reason,
+            },
+        )
+    }
+
+    /// Get field name from its tag number.
+    pub fn get_field_name(&self, tag: i32) -> Option<String> {
+        unsafe { FixDataDictionary_getFieldName(self.0, tag) }.map(read_checked_cstr)
+    }
+
+    /// Get field tag number from its name.
+    pub fn get_field_tag(&self, name: &str) -> Option<i32> {
+        let ffi_name = CString::new(name).ok()?;
+        let tag = unsafe { FixDataDictionary_getFieldTag(self.0, ffi_name.as_ptr()) };
+        (tag > 0).then_some(tag)
+    }
+
+    /// Get field type from its tag number.
+    pub fn get_field_type(&self, tag: i32) -> Option<FieldType> {
+        FieldType::try_from(unsafe { FixDataDictionary_getFieldType(self.0, tag) }).ok()
+    }
+
+    /// Check if a field only accepts a restricted list of values.
+    pub fn has_field_values(&self, tag: i32) -> bool {
+        ffi_code_to_bool(unsafe { FixDataDictionary_hasFieldValues(self.0, tag) })
+            .unwrap_or_default()
+    }
+
+    /// Check if value is part of the restricted list of values of a field.
+    ///
+    /// Always `false` when field accepts any value, see [`DataDictionary::has_field_values`].
+    pub fn is_field_value(&self, tag: i32, value: &str) -> bool {
+        let Ok(ffi_value) = CString::new(value) else {
+            return false;
+        };
+        ffi_code_to_bool(unsafe { FixDataDictionary_isFieldValue(self.0, tag, ffi_value.as_ptr()) })
+            .unwrap_or_default()
+    }
+
+    /// Get allowed values for a field.
+    ///
+    /// Returned list is empty if field accepts any value.
+    pub fn get_field_values(&self, tag: i32) -> Result<Vec<String>, QuickFixError> {
+        let mut values = Vec::new();
+        ffi_code_to_result(unsafe {
+            FixDataDictionary_readFieldValues(
+                self.0,
+                tag,
+                (&mut values as *mut Vec<String>).cast(),
+                push_string_to_vec,
+            )
+        })?;
+        Ok(values)
+    }
+
+    /// Get description of a field enum value (ex: `BUY` for `Side(54)=1`).
+    pub fn get_value_name(&self, tag: i32, value: &str) -> Option<String> {
+        let ffi_value = CString::new(value).ok()?;
+        unsafe { FixDataDictionary_getValueName(self.0, tag, ffi_value.as_ptr()) }
+            .map(read_checked_cstr)
+    }
+
+    /// Check if a body field is required for a given message type.
+    pub fn is_required_field(&self, msg_type: &str, tag: i32) -> bool {
+        let Ok(ffi_msg_type) = CString::new(msg_type) else {
+            return false;
+        };
+        ffi_code_to_bool(unsafe {
+            FixDataDictionary_isRequiredField(self.0, ffi_msg_type.as_ptr(), tag)
+        })
+        .unwrap_or_default()
+    }
+
+    /// Get required body fields for a given message type.
+    pub fn get_required_fields(&self, msg_type: &str) -> Result<Vec<i32>, QuickFixError> {
+        let ffi_msg_type = CString::new(msg_type)?;
+        let mut fields = Vec::new();
+        ffi_code_to_result(unsafe {
+            FixDataDictionary_readRequiredFields(
+                self.0,
+                ffi_msg_type.as_ptr(),
+                (&mut fields as *mut Vec<i32>).cast(),
+                push_int_to_vec,
+            )
+        })?;
+        Ok(fields)
+    }
+
+    /// Get delimiter (first field) of a repeating group in a message type.
+    ///
+    /// Nested groups are looked up too.
+    pub fn get_group_delimiter(&self, msg_type: &str, tag: i32) -> Option<i32> {
+        let ffi_msg_type = CString::new(msg_type).ok()?;
+        let delim =
+            unsafe { FixDataDictionary_getGroupDelimiter(self.0, ffi_msg_type.as_ptr(), tag) };
+        (delim > 0).then_some(delim)
+    }
+
+    /// Get fields of a repeating group in a message type, in their expected order.
+    ///
+    /// Nested groups are looked up too.
+    pub fn get_group_fields(&self, msg_type: &str, tag: i32) -> Option<Vec<i32>> {
+        let ffi_msg_type = CString::new(msg_type).ok()?;
+        let mut fields = Vec::new();
+        let found = ffi_code_to_bool(unsafe {
+            FixDataDictionary_readGroupFields(
+                self.0,
+                ffi_msg_type.as_ptr(),
+                tag,
+                (&mut fields as *mut Vec<i32>).cast(),
+                push_int_to_vec,
+            )
+        })
+        .ok()?;
+        found.then_some(fields)
+    }
 }
 
 impl fmt::Debug for DataDictionary {
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use crate::{DataDictionary, FieldMap, Message, QuickFixError};
+
+const TAG_APPL_VER_ID: i32 = 1128;
+
+/// Select dictionaries to use to parse FIXT 1.1 messages.
+///
+/// Header and trailer are parsed using the transport dictionary. Body is parsed
+/// using the application dictionary matching message `ApplVerID` (1128) or the
+/// default one when message has no `ApplVerID`.
+#[derive(Debug)]
+pub struct DataDictionaryProvider {
+    transport: DataDictionary,
+    applications: HashMap<String, DataDictionary>,
+    default_appl_ver_id: Option<String>,
+}
+
+impl DataDictionaryProvider {
+    /// Create a new struct from a transport dictionary (ex: `FIXT11.xml`).
+    pub fn new(transport: DataDictionary) -> Self {
+        Self {
+            transport,
+            applications: HashMap::new(),
+            default_appl_ver_id: None,
+        }
+    }
+
+    /// Register application dictionary for a given `ApplVerID` value (ex: `"9"` for FIX 5.0 SP2).
+    pub fn add_application<S: Into<String>>(&mut self, appl_ver_id: S, dictionary: DataDictionary) {
+        self.applications.insert(appl_ver_id.into(), dictionary);
+    }
+
+    /// Set `ApplVerID` to use when message does not have one.
+    ///
+    /// This is what `DefaultApplVerID` (1137) is used for during logon.
+    pub fn set_default_appl_ver_id<S: Into<String>>(&mut self, appl_ver_id: S) {
+        self.default_appl_ver_id = Some(appl_ver_id.into());
+    }
+
+    /// Create a new FIX message using transport and matching application dictionaries.
+    ///
+    /// Admin messages are parsed using transport dictionary only.
+    pub fn try_build_message(&self, text: &str) -> Result<Message, QuickFixError> {
+        // Data fields values may contain SOH, so let C++ parser find header fields.
+        let envelope = Message::try_from_bytes(text.as_bytes())?;
+        if envelope.is_admin() {
+            return self.transport.try_build_message(text);
+        }
+
+        let appl_ver_id = envelope
+            .with_header(|header| header.get_field(TAG_APPL_VER_ID))
+            .or_else(|| self.default_appl_ver_id.clone())
+            .ok_or_else(|| {
+                QuickFixError::invalid_argument("Cannot find ApplVerID for application message")
+            })?;
+
+        let application = self.applications.get(&appl_ver_id).ok_or_else(|| {
+            QuickFixError::UnsupportedVersion(format!(
+                "No application dictionary for ApplVerID: {appl_ver_id}"
+            ))
+        })?;
+
+        self.transport.try_build_app_message(application, text)
+    }
+}
@@ -161,6 +161,10 @@ impl_dictionary_item!(LogoutDay);
 pub struct LogoutTimeout(pub u16);
 impl_dictionary_item!(LogoutTimeout as i32);
 
+/// Session never stops, so it has no start / end time or day.
+pub struct NonStopSession(pub bool);
+impl_dictionary_item!(NonStopSession);
+
 /// Application version ID.
 pub struct DefaultApplVerID<'a>(pub &'a str);
 impl_dictionary_item!(DefaultApplVerID as String);
@@ -3,6 +3,8 @@ use std::ffi::{CStr, NulError};
 use quickfix_ffi::{Fix_clearLastErrorMessage, Fix_getLastErrorCode, Fix_getLastErrorMessage};
 use thiserror::Error;
 
+use crate::SessionRejectReason;
+
 /// Represent all possible error that can occurs with quickfix.
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
 pub enum QuickFixError {
@@ -177,6 +179,24 @@ impl QuickFixError {
     }
 }
 
+/// Message validation failure reported by [`crate::DataDictionary::validate`].
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[error("{reason}")]
+pub struct ValidationError {
+    /// Tag that caused the failure, if known.
+    pub tag: Option<i32>,
+    /// Why validation failed.
+    pub reason: QuickFixError,
+    /// Matching session reject reason, if failure is something a session would reject.
+    pub session_reject_reason: Option<SessionRejectReason>,
+}
+
+impl From<ValidationError> for QuickFixError {
+    fn from(value: ValidationError) -> Self {
+        value.reason
+    }
+}
+
 impl From<NulError> for QuickFixError {
     fn from(value: NulError) -> Self {
         Self::InvalidArgument(value.to_string())
@@ -0,0 +1,82 @@
+use std::{fmt, marker::PhantomData};
+
+use quickfix_ffi::{
+    FixFieldIterator_delete, FixFieldIterator_getDepth, FixFieldIterator_getTag,
+    FixFieldIterator_getValue, FixFieldIterator_isGroupStart, FixFieldIterator_next,
+    FixFieldIterator_t,
+};
+
+use crate::utils::{ffi_code_to_bool, read_checked_bytes};
+
+/// Iterator over every `(tag, value)` pair of a field map.
+///
+/// Fields are yielded in wire order. Repeating group entries are yielded
+/// right after their "NoXXX" counter field, recursively.
+///
+/// Iterating a [`crate::Message`] only yields its body fields: header and trailer are iterated
+/// separately, using [`crate::Message::with_header`] and [`crate::Message::with_trailer`].
+///
+/// Values are yielded as strings, with invalid UTF-8 replaced. Use
+/// [`FieldIterator::value_bytes`] to read binary `data` fields as is.
+pub struct FieldIterator<'a> {
+    inner: FixFieldIterator_t,
+    phantom_field_map: PhantomData<&'a ()>,
+}
+
+impl FieldIterator<'_> {
+    pub(crate) fn new(inner: FixFieldIterator_t) -> Self {
+        Self {
+            inner,
+            phantom_field_map: PhantomData,
+        }
+    }
+
+    /// Repeating group nesting level of last yielded field.
+    ///
+    /// Fields of iterated field map are at depth 0, fields of its group entries
+    /// at depth 1, and so on.
+    pub fn depth(&self) -> usize {
+        let depth = unsafe { FixFieldIterator_getDepth(self.inner) };
+        depth as usize
+    }
+
+    /// Get raw value of last yielded field, even if it contains NUL, SOH or non UTF-8 bytes.
+    pub fn value_bytes(&self) -> Vec<u8> {
+        let mut len = 0;
+        unsafe { FixFieldIterator_getValue(self.inner, &mut len) }
+            .map(|ptr| read_checked_bytes(ptr, len))
+            .unwrap_or_default()
+    }
+
+    /// Check if last yielded field is the first one of a repeating group entry.
+    pub fn is_group_start(&self) -> bool {
+        ffi_code_to_bool(unsafe { FixFieldIterator_isGroupStart(self.inner) }).unwrap_or_default()
+    }
+}
+
+impl Iterator for FieldIterator<'_> {
+    type Item = (i32, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !ffi_code_to_bool(unsafe { FixFieldIterator_next(self.inner) }).ok()? {
+            return None;
+        }
+
+        let tag = unsafe { FixFieldIterator_getTag(self.inner) };
+        let value = String::from_utf8_lossy(&self.value_bytes()).into_owned();
+
+        Some((tag, value))
+    }
+}
+
+impl fmt::Debug for FieldIterator<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FieldIterator").finish()
+    }
+}
+
+impl Drop for FieldIterator<'_> {
+    fn drop(&mut self) {
+        unsafe { FixFieldIterator_delete(self.inner) }
+    }
+}
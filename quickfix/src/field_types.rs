@@ -0,0 +1,436 @@
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{FromFixValue, QuickFixError};
+
+/// Type of a field as declared in a data dictionary.
+///
+/// Values match `FIX::TYPE::Type` in quickfix C++ library.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[repr(i32)]
+#[allow(missing_docs)]
+pub enum FieldType {
+    String = 1,
+    Char = 2,
+    Price = 3,
+    Int = 4,
+    Amt = 5,
+    Qty = 6,
+    Currency = 7,
+    MultipleValueString = 8,
+    MultipleStringValue = 9,
+    MultipleCharValue = 10,
+    Exchange = 11,
+    UtcTimeStamp = 12,
+    Boolean = 13,
+    LocalMktTime = 14,
+    LocalMktDate = 15,
+    Data = 16,
+    Float = 17,
+    PriceOffset = 18,
+    MonthYear = 19,
+    DayOfMonth = 20,
+    /// `UTCDate` or `UTCDateOnly`.
+    UtcDateOnly = 21,
+    UtcTimeOnly = 22,
+    NumInGroup = 23,
+    Percentage = 24,
+    SeqNum = 25,
+    TagNum = 26,
+    Length = 27,
+    Country = 28,
+    TzTimeOnly = 29,
+    TzTimeStamp = 30,
+    XmlData = 31,
+    Language = 32,
+    Xid = 33,
+    XidRef = 34,
+}
+
+impl TryFrom<i32> for FieldType {
+    type Error = QuickFixError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::String),
+            2 => Ok(Self::Char),
+            3 => Ok(Self::Price),
+            4 => Ok(Self::Int),
+            5 => Ok(Self::Amt),
+            6 => Ok(Self::Qty),
+            7 => Ok(Self::Currency),
+            8 => Ok(Self::MultipleValueString),
+            9 => Ok(Self::MultipleStringValue),
+            10 => Ok(Self::MultipleCharValue),
+            11 => Ok(Self::Exchange),
+            12 => Ok(Self::UtcTimeStamp),
+            13 => Ok(Self::Boolean),
+            14 => Ok(Self::LocalMktTime),
+            15 => Ok(Self::LocalMktDate),
+            16 => Ok(Self::Data),
+            17 => Ok(Self::Float),
+            18 => Ok(Self::PriceOffset),
+            19 => Ok(Self::MonthYear),
+            20 => Ok(Self::DayOfMonth),
+            21 => Ok(Self::UtcDateOnly),
+            22 => Ok(Self::UtcTimeOnly),
+            23 => Ok(Self::NumInGroup),
+            24 => Ok(Self::Percentage),
+            25 => Ok(Self::SeqNum),
+            26 => Ok(Self::TagNum),
+            27 => Ok(Self::Length),
+            28 => Ok(Self::Country),
+            29 => Ok(Self::TzTimeOnly),
+            30 => Ok(Self::TzTimeStamp),
+            31 => Ok(Self::XmlData),
+            32 => Ok(Self::Language),
+            33 => Ok(Self::Xid),
+            34 => Ok(Self::XidRef),
+            _ => Err(QuickFixError::InvalidArgument(format!(
+                "Invalid field type: {value}"
+            ))),
+        }
+    }
+}
+
+/// Number of digits used to write sub-second part of a FIX time value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+pub enum TimePrecision {
+    /// No sub-second part: `HH:MM:SS`.
+    Seconds,
+    /// Milliseconds: `HH:MM:SS.sss`.
+    #[default]
+    Milliseconds,
+    /// Microseconds: `HH:MM:SS.ssssss`.
+    Microseconds,
+    /// Nanoseconds: `HH:MM:SS.sssssssss`.
+    Nanoseconds,
+}
+
+impl TimePrecision {
+    const fn digits(self) -> usize {
+        match self {
+            Self::Seconds => 0,
+            Self::Milliseconds => 3,
+            Self::Microseconds => 6,
+            Self::Nanoseconds => 9,
+        }
+    }
+
+    const fn from_digits(digits: usize) -> Option<Self> {
+        match digits {
+            0 => Some(Self::Seconds),
+            3 => Some(Self::Milliseconds),
+            6 => Some(Self::Microseconds),
+            9 => Some(Self::Nanoseconds),
+            _ => None,
+        }
+    }
+}
+
+/// FIX `UTCDateOnly` value (`YYYYMMDD`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct UtcDateOnly {
+    /// Year (0 - 9999).
+    pub year: u16,
+    /// Month of the year (1 - 12).
+    pub month: u8,
+    /// Day of the month (1 - 31).
+    pub day: u8,
+}
+
+impl FromFixValue for UtcDateOnly {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        if value.len() != 8 {
+            return None;
+        }
+
+        let year = parse_digits(value, 0..4)?;
+        let month = parse_digits(value, 4..6)?;
+        let day = parse_digits(value, 6..8)?;
+
+        if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+            return None;
+        }
+
+        Some(Self {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        })
+    }
+}
+
+impl fmt::Display for UtcDateOnly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}{:02}{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// FIX `UTCTimeOnly` value (`HH:MM:SS[.sss][sss][sss]`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct UtcTimeOnly {
+    /// Hour of the day (0 - 23).
+    pub hour: u8,
+    /// Minute of the hour (0 - 59).
+    pub minute: u8,
+    /// Second of the minute (0 - 60, to allow leap second).
+    pub second: u8,
+    /// Sub-second part, in nanoseconds.
+    pub nanosecond: u32,
+    /// Precision used to write sub-second part.
+    pub precision: TimePrecision,
+}
+
+impl FromFixValue for UtcTimeOnly {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        let (hms, fraction) = match value.split_once('.') {
+            Some((hms, fraction)) if !fraction.is_empty() => (hms, Some(fraction)),
+            Some(_) => return None,
+            None => (value, None),
+        };
+
+        if hms.len() != 8 || hms.as_bytes()[2] != b':' || hms.as_bytes()[5] != b':' {
+            return None;
+        }
+
+        let hour = parse_digits(hms, 0..2)?;
+        let minute = parse_digits(hms, 3..5)?;
+        let second = parse_digits(hms, 6..8)?;
+
+        if hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+
+        let (nanosecond, precision) = match fraction {
+            Some(fraction) => {
+                let precision = TimePrecision::from_digits(fraction.len())?;
+                let value = parse_digits(fraction, 0..fraction.len())?;
+                (value * 10_u32.pow(9 - fraction.len() as u32), precision)
+            }
+            None => (0, TimePrecision::Seconds),
+        };
+
+        Some(Self {
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            nanosecond,
+            precision,
+        })
+    }
+}
+
+impl fmt::Display for UtcTimeOnly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+
+        let digits = self.precision.digits();
+        if digits > 0 {
+            let fraction = self.nanosecond / 10_u32.pow(9 - digits as u32);
+            write!(f, ".{fraction:0digits$}")?;
+        }
+        Ok(())
+    }
+}
+
+/// FIX `UTCTimestamp` value (`YYYYMMDD-HH:MM:SS[.sss][sss][sss]`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct UtcTimestamp {
+    /// Date part.
+    pub date: UtcDateOnly,
+    /// Time part.
+    pub time: UtcTimeOnly,
+}
+
+impl FromFixValue for UtcTimestamp {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        let (date, time) = value.split_once('-')?;
+        Some(Self {
+            date: UtcDateOnly::from_fix_value(date)?,
+            time: UtcTimeOnly::from_fix_value(time)?,
+        })
+    }
+}
+
+impl fmt::Display for UtcTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.date, self.time)
+    }
+}
+
+impl From<UtcTimestamp> for SystemTime {
+    fn from(value: UtcTimestamp) -> Self {
+        let days = days_from_civil(
+            value.date.year.into(),
+            value.date.month.into(),
+            value.date.day.into(),
+        );
+        let seconds = days * SECONDS_PER_DAY
+            + i64::from(value.time.hour) * 3600
+            + i64::from(value.time.minute) * 60
+            + i64::from(value.time.second);
+
+        let since_epoch = Duration::new(seconds.unsigned_abs(), 0);
+        let instant = if seconds >= 0 {
+            UNIX_EPOCH + since_epoch
+        } else {
+            UNIX_EPOCH - since_epoch
+        };
+        instant + Duration::from_nanos(value.time.nanosecond.into())
+    }
+}
+
+/// Optional day or week part of a FIX `MonthYear` value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MonthYearDetail {
+    /// Day of the month (1 - 31): `YYYYMMDD`.
+    Day(u8),
+    /// Week of the month (1 - 5): `YYYYMMwN`.
+    Week(u8),
+}
+
+/// FIX `MonthYear` value (`YYYYMM`, `YYYYMMDD` or `YYYYMMwN`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct MonthYear {
+    /// Year (0 - 9999).
+    pub year: u16,
+    /// Month of the year (1 - 12).
+    pub month: u8,
+    /// Optional day or week.
+    pub detail: Option<MonthYearDetail>,
+}
+
+impl FromFixValue for MonthYear {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        if value.len() != 6 && value.len() != 8 {
+            return None;
+        }
+
+        let year = parse_digits(value, 0..4)?;
+        let month = parse_digits(value, 4..6)?;
+        if !(1..=12).contains(&month) {
+            return None;
+        }
+
+        let detail = match value.get(6..)? {
+            "" => None,
+            week if week.starts_with('w') => {
+                let week = parse_digits(week, 1..2)?;
+                if !(1..=5).contains(&week) {
+                    return None;
+                }
+                Some(MonthYearDetail::Week(week as u8))
+            }
+            day => {
+                let day = parse_digits(day, 0..2)?;
+                if !(1..=days_in_month(year, month)).contains(&day) {
+                    return None;
+                }
+                Some(MonthYearDetail::Day(day as u8))
+            }
+        };
+
+        Some(Self {
+            year: year as u16,
+            month: month as u8,
+            detail,
+        })
+    }
+}
+
+impl fmt::Display for MonthYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}{:02}", self.year, self.month)?;
+        match self.detail {
+            Some(MonthYearDetail::Day(day)) => write!(f, "{day:02}"),
+            Some(MonthYearDetail::Week(week)) => write!(f, "w{week}"),
+            None => Ok(()),
+        }
+    }
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+// Days since 1970-01-01 of a proleptic Gregorian date, see http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn parse_digits(value: &str, range: std::ops::Range<usize>) -> Option<u32> {
+    let digits = value.get(range)?;
+    if digits.is_empty() || !digits.bytes().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use quickfix_ffi::FixFieldType_fromName;
+
+    use super::*;
+
+    #[test]
+    fn test_field_type_match_cpp_enum() {
+        for (name, field_type) in [
+            ("String", FieldType::String),
+            ("Char", FieldType::Char),
+            ("Price", FieldType::Price),
+            ("Int", FieldType::Int),
+            ("Amt", FieldType::Amt),
+            ("Qty", FieldType::Qty),
+            ("Currency", FieldType::Currency),
+            ("MultipleValueString", FieldType::MultipleValueString),
+            ("MultipleStringValue", FieldType::MultipleStringValue),
+            ("MultipleCharValue", FieldType::MultipleCharValue),
+            ("Exchange", FieldType::Exchange),
+            ("UtcTimeStamp", FieldType::UtcTimeStamp),
+            ("Boolean", FieldType::Boolean),
+            ("LocalMktTime", FieldType::LocalMktTime),
+            ("LocalMktDate", FieldType::LocalMktDate),
+            ("Data", FieldType::Data),
+            ("Float", FieldType::Float),
+            ("PriceOffset", FieldType::PriceOffset),
+            ("MonthYear", FieldType::MonthYear),
+            ("DayOfMonth", FieldType::DayOfMonth),
+            ("UtcDateOnly", FieldType::UtcDateOnly),
+            ("UtcTimeOnly", FieldType::UtcTimeOnly),
+            ("NumInGroup", FieldType::NumInGroup),
+            ("Percentage", FieldType::Percentage),
+            ("SeqNum", FieldType::SeqNum),
+            ("TagNum", FieldType::TagNum),
+            ("Length", FieldType::Length),
+            ("Country", FieldType::Country),
+            ("TzTimeOnly", FieldType::TzTimeOnly),
+            ("TzTimeStamp", FieldType::TzTimeStamp),
+            ("XmlData", FieldType::XmlData),
+            ("Language", FieldType::Language),
+            ("Xid", FieldType::Xid),
+            ("XidRef", FieldType::XidRef),
+        ] {
+            let name = CString::new(name).unwrap();
+            let cpp_value = unsafe { FixFieldType_fromName(name.as_ptr()) };
+            assert_eq!(field_type as i32, cpp_value, "{field_type:?}");
+            assert_eq!(FieldType::try_from(cpp_value).unwrap(), field_type);
+        }
+    }
+}
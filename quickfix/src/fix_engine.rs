@@ -0,0 +1,256 @@
+use std::sync::Arc;
+
+use crate::{
+    Acceptor, Application, ApplicationCallback, ConnectionHandler, Dictionary,
+    FfiMessageStoreFactory, FixSocketServerKind, Initiator, LogCallback, LogFactory, QuickFixError,
+    Session, SessionContainer, SessionId, SessionSettings,
+};
+
+#[derive(Debug)]
+enum EngineHandler<A, L, S>
+where
+    A: ApplicationCallback + 'static,
+    S: FfiMessageStoreFactory + 'static,
+    L: LogCallback + 'static,
+{
+    Acceptor(Acceptor<'static, A, L, S>),
+    Initiator(Initiator<'static, A, L, S>),
+}
+
+macro_rules! dispatch {
+    ($handler:expr, $inner:ident => $call:expr) => {
+        match $handler {
+            EngineHandler::Acceptor($inner) => $call,
+            EngineHandler::Initiator($inner) => $call,
+        }
+    };
+}
+
+/// Acceptor or initiator owning all its components.
+///
+/// Unlike [`Acceptor`] and [`Initiator`], engine does not borrow anything: it can be stored
+/// in a struct, returned from a function or moved to another thread. Engine is stopped and all
+/// its components are released when it is dropped.
+#[derive(Debug)]
+pub struct FixEngine<A, L, S>
+where
+    A: ApplicationCallback + 'static,
+    S: FfiMessageStoreFactory + 'static,
+    L: LogCallback + 'static,
+{
+    // Fields are dropped in declaration order: handler must be released before the components
+    // it uses.
+    handler: EngineHandler<A, L, S>,
+    _application: Application<'static, A>,
+    _log_factory: LogFactory<'static, L>,
+    // Boxed so the factory stays at the same address whatever `S` keeps inline.
+    store_factory: Box<S>,
+    settings: SessionSettings,
+    callbacks: Arc<A>,
+}
+
+impl<A, L, S> FixEngine<A, L, S>
+where
+    A: ApplicationCallback + 'static,
+    S: FfiMessageStoreFactory + 'static,
+    L: LogCallback + 'static,
+{
+    /// Try create a new engine accepting incoming connections.
+    pub fn try_new_acceptor(
+        settings: SessionSettings,
+        callbacks: Arc<A>,
+        store_factory: S,
+        logger: Arc<L>,
+        server_mode: FixSocketServerKind,
+    ) -> Result<Self, QuickFixError> {
+        Self::try_new(
+            settings,
+            callbacks,
+            store_factory,
+            logger,
+            |settings, application, store_factory, log_factory| {
+                // SAFETY: handler is released before the components (see field order).
+                unsafe {
+                    Acceptor::try_new_unchecked(
+                        settings,
+                        application,
+                        store_factory,
+                        log_factory,
+                        server_mode,
+                    )
+                }
+                .map(EngineHandler::Acceptor)
+            },
+        )
+    }
+
+    /// Try create a new engine establishing outgoing connections.
+    pub fn try_new_initiator(
+        settings: SessionSettings,
+        callbacks: Arc<A>,
+        store_factory: S,
+        logger: Arc<L>,
+        server_mode: FixSocketServerKind,
+    ) -> Result<Self, QuickFixError> {
+        Self::try_new(
+            settings,
+            callbacks,
+            store_factory,
+            logger,
+            |settings, application, store_factory, log_factory| {
+                // SAFETY: handler is released before the components (see field order).
+                unsafe {
+                    Initiator::try_new_unchecked(
+                        settings,
+                        application,
+                        store_factory,
+                        log_factory,
+                        server_mode,
+                    )
+                }
+                .map(EngineHandler::Initiator)
+            },
+        )
+    }
+
+    fn try_new(
+        settings: SessionSettings,
+        callbacks: Arc<A>,
+        store_factory: S,
+        logger: Arc<L>,
+        build_handler: impl FnOnce(
+            &SessionSettings,
+            &Application<'static, A>,
+            &S,
+            &LogFactory<'static, L>,
+        ) -> Result<EngineHandler<A, L, S>, QuickFixError>,
+    ) -> Result<Self, QuickFixError> {
+        let application = Application::try_new_owned(Arc::clone(&callbacks))?;
+        let log_factory = LogFactory::try_new_owned(logger)?;
+        let store_factory = Box::new(store_factory);
+
+        // C++ application and log factory are heap allocated, so moving their owners below
+        // does not invalidate the handler.
+        let handler = build_handler(&settings, &application, &store_factory, &log_factory)?;
+
+        Ok(Self {
+            handler,
+            _application: application,
+            _log_factory: log_factory,
+            store_factory,
+            settings,
+            callbacks,
+        })
+    }
+}
+
+impl<A, L, S> FixEngine<A, L, S>
+where
+    A: ApplicationCallback + 'static,
+    S: FfiMessageStoreFactory + 'static,
+    L: LogCallback + 'static,
+{
+    /// Check if engine accepts incoming connections.
+    pub fn is_acceptor(&self) -> bool {
+        matches!(self.handler, EngineHandler::Acceptor(_))
+    }
+
+    /// Get settings engine has been created with.
+    ///
+    /// Sessions added or removed later are not reflected here.
+    pub fn settings(&self) -> &SessionSettings {
+        &self.settings
+    }
+
+    /// Get application callbacks.
+    pub fn callbacks(&self) -> &Arc<A> {
+        &self.callbacks
+    }
+
+    /// Get message store factory.
+    pub fn store_factory(&self) -> &S {
+        &self.store_factory
+    }
+
+    /// List IDs of all sessions handled by this engine.
+    pub fn session_ids(&self) -> Result<Vec<SessionId>, QuickFixError> {
+        dispatch!(&self.handler, handler => handler.session_ids())
+    }
+
+    /// Add a new session while engine is running.
+    ///
+    /// See [`Acceptor::add_session`] and [`Initiator::add_session`].
+    pub fn add_session(
+        &mut self,
+        session_id: SessionId,
+        settings: Dictionary,
+    ) -> Result<(), QuickFixError> {
+        dispatch!(&mut self.handler, handler => handler.add_session(session_id, settings))
+    }
+
+    /// Logout and remove a session while engine is running.
+    pub fn remove_session(&mut self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        dispatch!(&mut self.handler, handler => handler.remove_session(session_id))
+    }
+
+    /// Borrow all sessions handled by this engine.
+    pub fn sessions(&self) -> Result<Vec<Session<'_>>, QuickFixError> {
+        dispatch!(&self.handler, handler => handler.sessions())
+    }
+}
+
+impl<A, L, S> ConnectionHandler for FixEngine<A, L, S>
+where
+    A: ApplicationCallback + 'static,
+    S: FfiMessageStoreFactory + 'static,
+    L: LogCallback + 'static,
+{
+    fn start(&mut self) -> Result<(), QuickFixError> {
+        dispatch!(&mut self.handler, handler => handler.start())
+    }
+
+    fn block(&mut self) -> Result<(), QuickFixError> {
+        dispatch!(&mut self.handler, handler => handler.block())
+    }
+
+    fn poll(&mut self) -> Result<bool, QuickFixError> {
+        dispatch!(&mut self.handler, handler => handler.poll())
+    }
+
+    fn stop(&mut self) -> Result<(), QuickFixError> {
+        dispatch!(&mut self.handler, handler => handler.stop())
+    }
+
+    fn is_logged_on(&self) -> Result<bool, QuickFixError> {
+        dispatch!(&self.handler, handler => handler.is_logged_on())
+    }
+
+    fn is_stopped(&self) -> Result<bool, QuickFixError> {
+        dispatch!(&self.handler, handler => handler.is_stopped())
+    }
+}
+
+impl<A, L, S> SessionContainer for FixEngine<A, L, S>
+where
+    A: ApplicationCallback + 'static,
+    S: FfiMessageStoreFactory + 'static,
+    L: LogCallback + 'static,
+{
+    fn session(&self, session_id: SessionId) -> Result<Session<'_>, QuickFixError> {
+        dispatch!(&self.handler, handler => handler.session(session_id))
+    }
+
+    fn session_settings(&self, session_id: &SessionId) -> Result<Dictionary, QuickFixError> {
+        dispatch!(&self.handler, handler => handler.session_settings(session_id))
+    }
+}
+
+// SAFETY: engine owns every C++ object it uses, and callbacks are already called from
+// quickfix threads.
+unsafe impl<A, L, S> Send for FixEngine<A, L, S>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+    S: FfiMessageStoreFactory + Send + 'static,
+    L: LogCallback + Send + Sync + 'static,
+{
+}
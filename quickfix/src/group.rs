@@ -2,13 +2,15 @@ use std::fmt;
 
 use quickfix_ffi::{
     FixGroup_addGroup, FixGroup_copy, FixGroup_copyGroup, FixGroup_delete, FixGroup_getDelim,
-    FixGroup_getField, FixGroup_getFieldId, FixGroup_new, FixGroup_removeField, FixGroup_setField,
+    FixGroup_getField, FixGroup_getFieldBytes, FixGroup_getFieldId, FixGroup_getFieldIterator,
+    FixGroup_getGroupCount, FixGroup_new, FixGroup_removeAllGroups, FixGroup_removeField,
+    FixGroup_removeGroup, FixGroup_replaceGroup, FixGroup_setField, FixGroup_setFieldBytes,
     FixGroup_t,
 };
 
 use crate::{
-    utils::{ffi_code_to_result, read_checked_cstr},
-    FieldMap, IntoFixValue, QuickFixError,
+    utils::{ffi_code_to_result, read_checked_bytes, read_checked_cstr},
+    FieldIterator, FieldMap, IntoFixValue, QuickFixError,
 };
 
 /// Base class for all FIX repeating groups.
@@ -58,6 +60,18 @@ impl FieldMap for Group {
         ffi_code_to_result(unsafe { FixGroup_setField(self.0, tag, fix_value.as_ptr()) })
     }
 
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        let mut len = 0;
+        unsafe { FixGroup_getFieldBytes(self.0, tag, &mut len) }
+            .map(|ptr| read_checked_bytes(ptr, len))
+    }
+
+    fn set_field_bytes(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixGroup_setFieldBytes(self.0, tag, value.as_ptr().cast(), value.len() as u64)
+        })
+    }
+
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError> {
         ffi_code_to_result(unsafe { FixGroup_removeField(self.0, tag) })
     }
@@ -70,6 +84,29 @@ impl FieldMap for Group {
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group> {
         unsafe { FixGroup_copyGroup(self.0, index, tag) }.map(Group)
     }
+
+    fn remove_group(&mut self, index: i32, tag: i32) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixGroup_removeGroup(self.0, index, tag) })
+    }
+
+    fn replace_group(&mut self, index: i32, group: &Group) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixGroup_replaceGroup(self.0, index, group.0) })
+    }
+
+    fn remove_all_groups(&mut self, tag: i32) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixGroup_removeAllGroups(self.0, tag) })
+    }
+
+    fn group_count(&self, tag: i32) -> usize {
+        let count = unsafe { FixGroup_getGroupCount(self.0, tag) };
+        count.try_into().unwrap_or_default()
+    }
+
+    fn iter_fields(&self) -> FieldIterator<'_> {
+        unsafe { FixGroup_getFieldIterator(self.0) }
+            .map(FieldIterator::new)
+            .expect("Fail to allocate FieldIterator")
+    }
 }
 
 impl Clone for Group {
@@ -2,12 +2,15 @@ use std::fmt;
 
 use quickfix_ffi::{
     FixHeader_addGroup, FixHeader_copy, FixHeader_copyGroup, FixHeader_delete, FixHeader_getField,
-    FixHeader_new, FixHeader_removeField, FixHeader_setField, FixHeader_t,
+    FixHeader_getFieldBytes, FixHeader_getFieldIterator, FixHeader_getGroupCount, FixHeader_new,
+    FixHeader_removeAllGroups, FixHeader_removeField, FixHeader_removeGroup,
+    FixHeader_replaceGroup, FixHeader_setField, FixHeader_setFieldBytes, FixHeader_t,
+    FixHeader_writeBytes,
 };
 
 use crate::{
-    utils::{ffi_code_to_result, read_checked_cstr},
-    FieldMap, Group, IntoFixValue, QuickFixError,
+    utils::{append_to_vec, ffi_code_to_result, read_checked_bytes, read_checked_cstr},
+    FieldIterator, FieldMap, Group, IntoFixValue, QuickFixError,
 };
 
 /// Header part of a FIX message.
@@ -18,6 +21,15 @@ impl Header {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Append header fields bytes to given buffer.
+    ///
+    /// Buffer is not cleared, so it can be reused from one call to another to avoid new allocations.
+    pub fn write_fix_bytes(&self, buffer: &mut Vec<u8>) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixHeader_writeBytes(self.0, (buffer as *mut Vec<u8>).cast(), append_to_vec)
+        })
+    }
 }
 
 impl FieldMap for Header {
@@ -30,6 +42,18 @@ impl FieldMap for Header {
         ffi_code_to_result(unsafe { FixHeader_setField(self.0, tag, fix_value.as_ptr()) })
     }
 
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        let mut len = 0;
+        unsafe { FixHeader_getFieldBytes(self.0, tag, &mut len) }
+            .map(|ptr| read_checked_bytes(ptr, len))
+    }
+
+    fn set_field_bytes(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixHeader_setFieldBytes(self.0, tag, value.as_ptr().cast(), value.len() as u64)
+        })
+    }
+
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError> {
         ffi_code_to_result(unsafe { FixHeader_removeField(self.0, tag) })
     }
@@ -42,6 +66,29 @@ impl FieldMap for Header {
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group> {
         unsafe { FixHeader_copyGroup(self.0, index, tag) }.map(Group)
     }
+
+    fn remove_group(&mut self, index: i32, tag: i32) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixHeader_removeGroup(self.0, index, tag) })
+    }
+
+    fn replace_group(&mut self, index: i32, group: &Group) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixHeader_replaceGroup(self.0, index, group.0) })
+    }
+
+    fn remove_all_groups(&mut self, tag: i32) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixHeader_removeAllGroups(self.0, tag) })
+    }
+
+    fn group_count(&self, tag: i32) -> usize {
+        let count = unsafe { FixHeader_getGroupCount(self.0, tag) };
+        count.try_into().unwrap_or_default()
+    }
+
+    fn iter_fields(&self) -> FieldIterator<'_> {
+        unsafe { FixHeader_getFieldIterator(self.0) }
+            .map(FieldIterator::new)
+            .expect("Fail to allocate FieldIterator")
+    }
 }
 
 impl Clone for Header {
@@ -0,0 +1,109 @@
+use std::{
+    collections::HashMap,
+    sync::{Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use crate::{FieldMap, Message, QuickFixError, Session, SessionId};
+
+const TAG_TEST_REQ_ID: i32 = 112;
+const MSG_TYPE_HEARTBEAT: &str = "0";
+
+/// Test request sent and not yet consumed.
+#[derive(Debug)]
+struct PendingTestRequest {
+    sent_at: Instant,
+    latency: Option<Duration>,
+}
+
+/// Match heartbeats answering test requests, to check a session link is alive and measure its
+/// round-trip latency.
+///
+/// Tracker does not receive messages by itself: received admin messages must be forwarded to
+/// [`HeartbeatTracker::on_msg_from_admin`] from [`crate::ApplicationCallback::on_msg_from_admin`].
+#[derive(Debug, Default)]
+pub struct HeartbeatTracker {
+    pending: Mutex<HashMap<(String, String), PendingTestRequest>>,
+    heartbeat_received: Condvar,
+}
+
+impl HeartbeatTracker {
+    /// Create a new tracker, with no pending test request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send a `TestRequest` and start waiting for its matching `Heartbeat`.
+    pub fn send_test_request(
+        &self,
+        session: &mut Session,
+        test_req_id: &str,
+    ) -> Result<bool, QuickFixError> {
+        let key = (session.session_id().to_repr(), test_req_id.to_string());
+        self.lock().insert(
+            key.clone(),
+            PendingTestRequest {
+                sent_at: Instant::now(),
+                latency: None,
+            },
+        );
+
+        let result = session.send_test_request(test_req_id);
+        if !matches!(result, Ok(true)) {
+            self.lock().remove(&key);
+        }
+        result
+    }
+
+    /// Record received `Heartbeat` answering a pending test request.
+    pub fn on_msg_from_admin(&self, msg: &Message, session: &SessionId) {
+        if msg.msg_type().as_deref() != Some(MSG_TYPE_HEARTBEAT) {
+            return;
+        }
+        let Some(test_req_id) = msg.get_field(TAG_TEST_REQ_ID) else {
+            return;
+        };
+
+        let mut pending = self.lock();
+        if let Some(request) = pending.get_mut(&(session.to_repr(), test_req_id)) {
+            if request.latency.is_none() {
+                request.latency = Some(request.sent_at.elapsed());
+                self.heartbeat_received.notify_all();
+            }
+        }
+    }
+
+    /// Get round-trip latency of a test request, if its `Heartbeat` has been received.
+    pub fn latency(&self, session: &SessionId, test_req_id: &str) -> Option<Duration> {
+        self.lock()
+            .get(&(session.to_repr(), test_req_id.to_string()))
+            .and_then(|request| request.latency)
+    }
+
+    /// Wait for the `Heartbeat` of a test request, and return its round-trip latency.
+    ///
+    /// Test request is no more tracked once this function returns, so a late heartbeat is
+    /// ignored. Returns `None` on timeout or if the test request was not sent with this tracker.
+    pub fn wait_heartbeat(
+        &self,
+        session: &SessionId,
+        test_req_id: &str,
+        timeout: Duration,
+    ) -> Option<Duration> {
+        let key = (session.to_repr(), test_req_id.to_string());
+        let (mut pending, _) = self
+            .heartbeat_received
+            .wait_timeout_while(self.lock(), timeout, |pending| {
+                pending
+                    .get(&key)
+                    .is_some_and(|request| request.latency.is_none())
+            })
+            .unwrap_or_else(|err| err.into_inner());
+        pending.remove(&key).and_then(|request| request.latency)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<(String, String), PendingTestRequest>> {
+        // Map stays consistent even if a thread panicked while holding the lock.
+        self.pending.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
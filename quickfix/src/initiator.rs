@@ -1,14 +1,15 @@
 use std::marker::PhantomData;
 
 use quickfix_ffi::{
-    FixInitiator_block, FixInitiator_delete, FixInitiator_getSession, FixInitiator_isLoggedOn,
-    FixInitiator_isStopped, FixInitiator_new, FixInitiator_poll, FixInitiator_start,
-    FixInitiator_stop, FixInitiator_t,
+    FixInitiator_addSession, FixInitiator_block, FixInitiator_delete, FixInitiator_getSession,
+    FixInitiator_getSessionSettings, FixInitiator_isLoggedOn, FixInitiator_isStopped,
+    FixInitiator_new, FixInitiator_poll, FixInitiator_readSessions, FixInitiator_removeSession,
+    FixInitiator_start, FixInitiator_stop, FixInitiator_t,
 };
 
 use crate::{
-    utils::{ffi_code_to_bool, ffi_code_to_result},
-    Application, ApplicationCallback, ConnectionHandler, FfiMessageStoreFactory,
+    utils::{ffi_code_to_bool, ffi_code_to_result, push_session_id_to_vec},
+    Application, ApplicationCallback, ConnectionHandler, Dictionary, FfiMessageStoreFactory,
     FixSocketServerKind, LogCallback, LogFactory, QuickFixError, Session, SessionContainer,
     SessionId, SessionSettings,
 };
@@ -40,6 +41,31 @@ where
         store_factory: &'a S,
         log_factory: &'a LogFactory<L>,
         server_mode: FixSocketServerKind,
+    ) -> Result<Self, QuickFixError> {
+        // SAFETY: components are borrowed for the initiator lifetime.
+        unsafe {
+            Self::try_new_unchecked(
+                settings,
+                application,
+                store_factory,
+                log_factory,
+                server_mode,
+            )
+        }
+    }
+
+    /// Create new struct without binding its lifetime to its components.
+    ///
+    /// # Safety
+    ///
+    /// C++ objects behind `application`, `store_factory` and `log_factory` must outlive
+    /// the returned value.
+    pub(crate) unsafe fn try_new_unchecked(
+        settings: &SessionSettings,
+        application: &Application<A>,
+        store_factory: &S,
+        log_factory: &LogFactory<L>,
+        server_mode: FixSocketServerKind,
     ) -> Result<Self, QuickFixError> {
         match unsafe {
             FixInitiator_new(
@@ -60,6 +86,49 @@ where
             None => Err(QuickFixError::from_last_error()),
         }
     }
+
+    /// List IDs of all sessions handled by this initiator.
+    pub fn session_ids(&self) -> Result<Vec<SessionId>, QuickFixError> {
+        let mut session_ids = Vec::new();
+        ffi_code_to_result(unsafe {
+            FixInitiator_readSessions(
+                self.inner,
+                (&mut session_ids as *mut Vec<SessionId>).cast(),
+                push_session_id_to_vec,
+            )
+        })?;
+        Ok(session_ids)
+    }
+
+    /// Add a new session while initiator is running.
+    ///
+    /// Settings are merged with the global ones and the session uses the initiator message store
+    /// and log factories. Other sessions are not affected.
+    ///
+    /// The session connects to its target on next reconnection attempt (see `ReconnectInterval`).
+    pub fn add_session(
+        &mut self,
+        session_id: SessionId,
+        settings: Dictionary,
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixInitiator_addSession(self.inner, session_id.0, settings.0) })
+    }
+
+    /// Logout and remove a session while initiator is running.
+    ///
+    /// Session cannot be looked up anymore and its ID can be added again right away. It is released
+    /// once its connection is closed.
+    pub fn remove_session(&mut self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixInitiator_removeSession(self.inner, session_id.0) })
+    }
+
+    /// Borrow all sessions handled by this initiator.
+    pub fn sessions(&self) -> Result<Vec<Session<'_>>, QuickFixError> {
+        self.session_ids()?
+            .into_iter()
+            .map(|session_id| self.session(session_id))
+            .collect()
+    }
 }
 
 impl<A, L, S> ConnectionHandler for Initiator<'_, A, L, S>
@@ -111,6 +180,14 @@ where
                 })
         }
     }
+
+    fn session_settings(&self, session_id: &SessionId) -> Result<Dictionary, QuickFixError> {
+        unsafe { FixInitiator_getSessionSettings(self.inner, session_id.0) }
+            .map(Dictionary)
+            .ok_or_else(|| {
+                QuickFixError::SessionNotFound(format!("No session found: {session_id:?}"))
+            })
+    }
 }
 
 impl<A, L, S> Drop for Initiator<'_, A, L, S>
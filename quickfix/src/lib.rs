@@ -18,6 +18,9 @@
 - Spec driven code generation of type-safe FIX messages, fields, and repeating groups.
 - Session state storage options: SQL, File, In Memory.
 - Logging options: stdout, stderr, [log](https://crates.io/crates/log) or any other crate if you implement your own trait.
+- Optional [serde](https://serde.rs) support for messages, headers, trailers and repeating groups (`serde` feature).
+- Application callbacks as events over a `std::sync::mpsc` or [crossbeam](https://crates.io/crates/crossbeam-channel) channel (`crossbeam-channel` feature).
+- Optional [tokio](https://tokio.rs) support: async event stream, send, logon wait and shutdown (`tokio` feature).
 
 ## Project status
 
@@ -106,47 +109,83 @@ acceptor.stop()?;
 
 mod acceptor;
 mod application;
+mod channel_application;
 mod data_dictionary;
+mod data_dictionary_provider;
 mod days;
 mod dictionary;
 /// Common dictionary configuration parameters.
 pub mod dictionary_item;
 mod error;
+mod field_iterator;
+mod field_types;
+mod fix_engine;
 mod group;
 mod header;
+mod heartbeat_tracker;
 mod initiator;
 mod log_factory;
 mod message;
+mod message_formatter;
 mod message_store_factory;
+mod outbound_buffer;
+mod panic_policy;
 mod session;
 mod session_id;
+mod session_reject_reason;
+mod session_schedule;
 mod session_settings;
+mod throttle;
 mod trailer;
 
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "tokio")]
+mod tokio_support;
+
 mod utils;
 
 use std::ffi::{CString, NulError};
 
 pub use acceptor::Acceptor;
 pub use application::{
-    Application, ApplicationCallback, MsgFromAdminError, MsgFromAppError, MsgToAppError,
+    Application, ApplicationCallback, LogonCredentials, LogonRequestError, MsgFromAdminError,
+    MsgFromAppError, MsgToAdminError, MsgToAppError, ResendAction, ResendContext,
+};
+pub use channel_application::{
+    BackpressurePolicy, ChannelApplication, FixEvent, FromAppReply, ReplyTimeoutPolicy,
 };
 pub use data_dictionary::DataDictionary;
+pub use data_dictionary_provider::DataDictionaryProvider;
 pub use days::DayOfWeek;
 pub use dictionary::Dictionary;
-pub use error::QuickFixError;
+pub use error::{QuickFixError, ValidationError};
+pub use field_iterator::FieldIterator;
+pub use field_types::{
+    FieldType, MonthYear, MonthYearDetail, TimePrecision, UtcDateOnly, UtcTimeOnly, UtcTimestamp,
+};
+pub use fix_engine::FixEngine;
 pub use group::Group;
 pub use header::Header;
+pub use heartbeat_tracker::HeartbeatTracker;
 pub use initiator::Initiator;
 pub use log_factory::{LogCallback, LogFactory, NullLogger, StdLogger};
 pub use message::Message;
+pub use message_formatter::{FieldDescriber, FormatLayout, MessageFormatter};
 pub use message_store_factory::{
     FfiMessageStoreFactory, FileMessageStoreFactory, MemoryMessageStoreFactory,
     NullMessageStoreFactory,
 };
-pub use session::{send_to_target, Session};
+pub use outbound_buffer::{BufferedSend, OutboundBuffer};
+pub use panic_policy::{CallbackPanic, PanicPolicy};
+pub use session::{send_to_target, Session, SessionStatus};
 pub use session_id::SessionId;
+pub use session_reject_reason::SessionRejectReason;
+pub use session_schedule::{LocalTimeOffset, SessionSchedule};
 pub use session_settings::SessionSettings;
+pub use throttle::{
+    OutboundThrottle, SendPriority, ThrottleFlush, ThrottleMetrics, ThrottlePolicy, ThrottledSend,
+};
 pub use trailer::Trailer;
 
 #[cfg(feature = "log")]
@@ -155,6 +194,10 @@ pub use log_factory::RustLogger;
 pub use message_store_factory::mysql::MySqlMessageStoreFactory;
 #[cfg(feature = "build-with-postgres")]
 pub use message_store_factory::postgres::PostgresMessageStoreFactory;
+#[cfg(feature = "serde")]
+pub use serde_support::{FieldNamesSeed, WithFieldNames};
+#[cfg(feature = "tokio")]
+pub use tokio_support::{AsyncSender, FixEventStream};
 
 /// Permit control of an underlying socket connection.
 pub trait ConnectionHandler {
@@ -185,6 +228,21 @@ pub trait SessionContainer {
     ///
     /// Session is lookup using its ID.
     fn session(&self, session_id: SessionId) -> Result<Session<'_>, QuickFixError>;
+
+    /// Get a copy of the settings a session has been created with.
+    ///
+    /// Default implementation fails, as settings are not known by every container.
+    fn session_settings(&self, session_id: &SessionId) -> Result<Dictionary, QuickFixError> {
+        Err(QuickFixError::invalid_argument(format!(
+            "Settings of session {} are not available",
+            session_id.to_repr()
+        )))
+    }
+
+    /// Take a snapshot of a session state.
+    fn session_status(&self, session_id: SessionId) -> Result<SessionStatus, QuickFixError> {
+        self.session(session_id)?.status()
+    }
 }
 
 /// Convert object to FIX value.
@@ -242,6 +300,74 @@ impl IntoFixValue for bool {
     }
 }
 
+impl IntoFixValue for char {
+    fn into_fix_value(self) -> Result<CString, NulError> {
+        CString::new(self.to_string())
+    }
+}
+
+impl_into_fix_value!(UtcDateOnly);
+impl_into_fix_value!(UtcTimeOnly);
+impl_into_fix_value!(UtcTimestamp);
+impl_into_fix_value!(MonthYear);
+
+/// Convert FIX value to object.
+///
+/// This is the reverse operation of [`IntoFixValue`].
+pub trait FromFixValue: Sized {
+    /// Parse FIX value. Return `None` if value cannot be converted.
+    fn from_fix_value(value: &str) -> Option<Self>;
+}
+
+macro_rules! impl_from_fix_value {
+    ($t:ty) => {
+        impl FromFixValue for $t {
+            fn from_fix_value(value: &str) -> Option<Self> {
+                value.parse().ok()
+            }
+        }
+    };
+}
+
+impl_from_fix_value!(u8);
+impl_from_fix_value!(u16);
+impl_from_fix_value!(u32);
+impl_from_fix_value!(u64);
+impl_from_fix_value!(u128);
+impl_from_fix_value!(usize);
+impl_from_fix_value!(i8);
+impl_from_fix_value!(i16);
+impl_from_fix_value!(i32);
+impl_from_fix_value!(i64);
+impl_from_fix_value!(i128);
+impl_from_fix_value!(isize);
+impl_from_fix_value!(f32);
+impl_from_fix_value!(f64);
+impl_from_fix_value!(char);
+
+impl FromFixValue for String {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+impl FromFixValue for bool {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        match value {
+            "Y" => Some(true),
+            "N" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Parse FIX `MultipleValueString` / `MultipleCharValue` (space separated values).
+impl<T: FromFixValue> FromFixValue for Vec<T> {
+    fn from_fix_value(value: &str) -> Option<Self> {
+        value.split(' ').map(T::from_fix_value).collect()
+    }
+}
+
 /// Stores and organizes a collection of Fields.
 ///
 /// This is the basis for a message, header, and trailer.  This collection
@@ -250,9 +376,38 @@ pub trait FieldMap {
     /// Get field value from its tag number.
     fn get_field(&self, tag: i32) -> Option<String>;
 
+    /// Get field value from its tag number and convert it to `T`.
+    ///
+    /// Return `Ok(None)` when field is not set and `QuickFixError::FieldConvertError`
+    /// when value cannot be converted.
+    fn get_field_as<T: FromFixValue>(&self, tag: i32) -> Result<Option<T>, QuickFixError> {
+        self.get_field(tag)
+            .map(|value| {
+                T::from_fix_value(&value).ok_or_else(|| {
+                    QuickFixError::FieldConvertError(format!(
+                        "Cannot convert field {tag} with value {value:?} to {}",
+                        std::any::type_name::<T>()
+                    ))
+                })
+            })
+            .transpose()
+    }
+
     /// Set field value for a given tag number.
     fn set_field<V: IntoFixValue>(&mut self, tag: i32, value: V) -> Result<(), QuickFixError>;
 
+    /// Get raw field value from its tag number.
+    ///
+    /// Unlike [`FieldMap::get_field`], value is returned as is, even if it contains NUL or SOH bytes.
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>>;
+
+    /// Set raw field value for a given tag number.
+    ///
+    /// This is useful for `data` fields (like `RawData`, `XmlData`, `EncodedText`, ...)
+    /// which may contain NUL or SOH bytes. Matching length field is not updated
+    /// and must be set by caller.
+    fn set_field_bytes(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError>;
+
     /// Remove a field from  collection.
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError>;
 
@@ -261,6 +416,27 @@ pub trait FieldMap {
 
     /// Clone group part for a given tag and group index.
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group>;
+
+    /// Remove group part for a given tag and group index (starting at 1).
+    ///
+    /// Group count field is updated, or removed with the last group.
+    fn remove_group(&mut self, index: i32, tag: i32) -> Result<(), QuickFixError>;
+
+    /// Replace group part at a given index (starting at 1) with a copy of `group`.
+    ///
+    /// Group tag is read from [`Group::field_id`].
+    fn replace_group(&mut self, index: i32, group: &Group) -> Result<(), QuickFixError>;
+
+    /// Remove all groups for a given tag, including group count field.
+    fn remove_all_groups(&mut self, tag: i32) -> Result<(), QuickFixError>;
+
+    /// Get number of groups for a given tag.
+    fn group_count(&self, tag: i32) -> usize;
+
+    /// Iterate over every field in wire order, including nested repeating groups.
+    ///
+    /// Header and trailer of a `Message` are not included.
+    fn iter_fields(&self) -> FieldIterator<'_>;
 }
 
 /// Allow reading value (aka property) from a foreign (C++) object.
@@ -3,14 +3,18 @@ use std::{
     io::{self, Write},
     marker::PhantomData,
     mem::ManuallyDrop,
-    panic::catch_unwind,
+    ptr::NonNull,
+    sync::Arc,
 };
 
 use quickfix_ffi::{
     FixLogCallbacks_t, FixLogFactory_delete, FixLogFactory_new, FixLogFactory_t, FixSessionID_t,
 };
 
-use crate::{utils::from_ffi_str, QuickFixError, SessionId};
+use crate::{
+    panic_policy::CallbackContext, utils::from_ffi_str, CallbackPanic, PanicPolicy, QuickFixError,
+    SessionId,
+};
 
 /// Log event that can occurs in quickfix library.
 ///
@@ -28,7 +32,12 @@ pub trait LogCallback {
 }
 
 /// Logging factory.
-pub struct LogFactory<'a, C: LogCallback>(pub(crate) FixLogFactory_t, PhantomData<&'a C>);
+pub struct LogFactory<'a, C: LogCallback>(
+    pub(crate) FixLogFactory_t,
+    PhantomData<&'a C>,
+    /// Logger and panic policy, given back by C++ code to each callback.
+    NonNull<CallbackContext<C>>,
+);
 
 impl<'a, C> LogFactory<'a, C>
 where
@@ -36,17 +45,48 @@ where
 {
     /// Create new struct from given logger trait.
     pub fn try_new(callbacks: &'a C) -> Result<Self, QuickFixError> {
-        match unsafe {
-            FixLogFactory_new(
-                callbacks as *const C as *const ffi::c_void,
-                &Self::CALLBACKS,
-            )
-        } {
-            Some(fix_log_factory) => Ok(Self(fix_log_factory, PhantomData)),
-            None => Err(QuickFixError::from_last_error()),
+        Self::try_from_context(CallbackContext::new_borrowed(callbacks))
+    }
+
+    /// Create new struct owning its logger.
+    ///
+    /// Logger can still be shared with other code. Use `Arc::from` to build it from a `Box<C>`.
+    pub fn try_new_owned(callbacks: Arc<C>) -> Result<LogFactory<'static, C>, QuickFixError> {
+        LogFactory::try_from_context(CallbackContext::new_owned(callbacks))
+    }
+
+    fn try_from_context(context: NonNull<CallbackContext<C>>) -> Result<Self, QuickFixError> {
+        match unsafe { FixLogFactory_new(context.as_ptr() as *const ffi::c_void, &Self::CALLBACKS) }
+        {
+            Some(fix_log_factory) => Ok(Self(fix_log_factory, PhantomData, context)),
+            None => {
+                unsafe { CallbackContext::release(context) };
+                Err(QuickFixError::from_last_error())
+            }
         }
     }
 
+    /// Set what to do when a logger callback panics.
+    ///
+    /// Default is [`PanicPolicy::Ignore`]. There is no message to reject here:
+    /// [`PanicPolicy::Reject`] only logs the panic.
+    pub fn with_panic_policy(self, policy: PanicPolicy) -> Self {
+        // SAFETY: factory is not used by any acceptor / initiator yet, since it is owned.
+        unsafe { CallbackContext::set_policy(self.2, policy) };
+        self
+    }
+
+    /// Set a function called with every panic caught from a logger callback, before applying
+    /// panic policy.
+    pub fn with_panic_hook<F>(self, hook: F) -> Self
+    where
+        F: Fn(&CallbackPanic<'_>) + Send + Sync + 'static,
+    {
+        // SAFETY: factory is not used by any acceptor / initiator yet, since it is owned.
+        unsafe { CallbackContext::set_hook(self.2, Box::new(hook)) };
+        self
+    }
+
     const CALLBACKS: FixLogCallbacks_t = FixLogCallbacks_t {
         onIncoming: Self::on_incoming,
         onOutgoing: Self::on_outgoing,
@@ -58,11 +98,11 @@ where
         session_id_ptr: Option<FixSessionID_t>,
         msg_ptr: *const ffi::c_char,
     ) {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
         let session_id = session_id_ptr.map(|ptr| ManuallyDrop::new(SessionId(ptr)));
         let msg = unsafe { from_ffi_str(msg_ptr) };
 
-        let _ = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
+        context.call("on_incoming", session_id.as_deref(), |this| {
             this.on_incoming(session_id.as_deref(), msg);
         });
     }
@@ -72,11 +112,11 @@ where
         session_id_ptr: Option<FixSessionID_t>,
         msg_ptr: *const ffi::c_char,
     ) {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
         let session_id = session_id_ptr.map(|ptr| ManuallyDrop::new(SessionId(ptr)));
         let msg = unsafe { from_ffi_str(msg_ptr) };
 
-        let _ = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
+        context.call("on_outgoing", session_id.as_deref(), |this| {
             this.on_outgoing(session_id.as_deref(), msg);
         });
     }
@@ -86,11 +126,11 @@ where
         session_id_ptr: Option<FixSessionID_t>,
         msg_ptr: *const ffi::c_char,
     ) {
+        let context = unsafe { CallbackContext::<C>::from_ffi(data) };
         let session_id = session_id_ptr.map(|ptr| ManuallyDrop::new(SessionId(ptr)));
         let msg = unsafe { from_ffi_str(msg_ptr) };
 
-        let _ = catch_unwind(|| {
-            let this = unsafe { &*(data as *const C) };
+        context.call("on_event", session_id.as_deref(), |this| {
             this.on_event(session_id.as_deref(), msg);
         });
     }
@@ -104,7 +144,8 @@ impl<C: LogCallback> fmt::Debug for LogFactory<'_, C> {
 
 impl<C: LogCallback> Drop for LogFactory<'_, C> {
     fn drop(&mut self) {
-        unsafe { FixLogFactory_delete(self.0) }
+        unsafe { FixLogFactory_delete(self.0) };
+        unsafe { CallbackContext::release(self.2) };
     }
 }
 
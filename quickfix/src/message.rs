@@ -2,20 +2,27 @@ use std::{ffi::CString, fmt, mem::ManuallyDrop};
 
 use quickfix_ffi::{
     FixMessage_addGroup, FixMessage_copy, FixMessage_copyGroup, FixMessage_copyHeader,
-    FixMessage_copyTrailer, FixMessage_delete, FixMessage_fromString, FixMessage_getField,
-    FixMessage_getGroupRef, FixMessage_getHeaderRef, FixMessage_getStringLen,
-    FixMessage_getTrailerRef, FixMessage_new, FixMessage_readString, FixMessage_removeField,
-    FixMessage_setField, FixMessage_t,
+    FixMessage_copyTrailer, FixMessage_delete, FixMessage_fromBytes, FixMessage_fromString,
+    FixMessage_getField, FixMessage_getFieldBytes, FixMessage_getFieldIterator,
+    FixMessage_getGroupCount, FixMessage_getGroupRef, FixMessage_getHeaderRef,
+    FixMessage_getStringLen, FixMessage_getTrailerRef, FixMessage_isAdmin, FixMessage_isApp,
+    FixMessage_new, FixMessage_readString, FixMessage_removeAllGroups, FixMessage_removeField,
+    FixMessage_removeGroup, FixMessage_replaceGroup, FixMessage_reverseRoute, FixMessage_setField,
+    FixMessage_setFieldBytes, FixMessage_t, FixMessage_writeBytes,
 };
 
 use crate::{
     group::Group,
     header::Header,
     trailer::Trailer,
-    utils::{ffi_code_to_result, read_checked_cstr},
-    FieldMap, IntoFixValue, QuickFixError,
+    utils::{
+        append_to_vec, ffi_code_to_bool, ffi_code_to_result, read_checked_bytes, read_checked_cstr,
+    },
+    FieldIterator, FieldMap, IntoFixValue, QuickFixError,
 };
 
+const TAG_MSG_TYPE: i32 = 35;
+
 /// Base class for all FIX messages.
 pub struct Message(pub(crate) FixMessage_t);
 
@@ -26,6 +33,9 @@ impl Message {
     }
 
     /// Try create new struct from raw text message.
+    ///
+    /// Standard `data` fields are read using their length field, so their values may contain
+    /// SOH bytes.
     pub fn try_from_text(text: &str) -> Result<Self, QuickFixError> {
         let ffi_text = CString::new(text)?;
         unsafe { FixMessage_fromString(ffi_text.as_ptr()) }
@@ -33,6 +43,43 @@ impl Message {
             .ok_or_else(QuickFixError::from_last_error)
     }
 
+    /// Try create new struct from raw message bytes.
+    ///
+    /// Unlike [`Message::try_from_text`], input may also contain NUL bytes in `data` fields values.
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, QuickFixError> {
+        unsafe { FixMessage_fromBytes(data.as_ptr().cast(), data.len() as u64) }
+            .map(Self)
+            .ok_or_else(QuickFixError::from_last_error)
+    }
+
+    /// Create a new message routed back to sender of `other`.
+    ///
+    /// `BeginString` is copied, `SenderCompID` / `TargetCompID` are swapped and
+    /// `OnBehalfOf*` / `DeliverTo*` routing fields are reversed. This is what
+    /// `FIX::Message::reverseRoute` does.
+    pub fn new_reply_to(other: &Message) -> Result<Self, QuickFixError> {
+        let msg = Self::new();
+        other.with_header(|header| {
+            ffi_code_to_result(unsafe { FixMessage_reverseRoute(msg.0, header.0) })
+        })?;
+        Ok(msg)
+    }
+
+    /// Read message type (`MsgType` field from header).
+    pub fn msg_type(&self) -> Option<String> {
+        self.with_header(|header| header.get_field(TAG_MSG_TYPE))
+    }
+
+    /// Check if message is an admin message (heartbeat, logon, logout, reject, ...).
+    pub fn is_admin(&self) -> bool {
+        ffi_code_to_bool(unsafe { FixMessage_isAdmin(self.0) }).unwrap_or_default()
+    }
+
+    /// Check if message is an application message.
+    pub fn is_app(&self) -> bool {
+        ffi_code_to_bool(unsafe { FixMessage_isApp(self.0) }).unwrap_or_default()
+    }
+
     /// Try reading underlying struct buffer as a vector of bytes.
     ///
     /// # Performances
@@ -42,6 +89,8 @@ impl Message {
     /// String will be generated twice in C++ code:
     /// - Once for getting a safe buffer length.
     /// - Then to copy buffer to rust "memory".
+    ///
+    /// Prefer [`Message::write_fix_bytes`] when performance matters.
     pub fn to_fix_raw_bytes(&self) -> Result<Vec<u8>, QuickFixError> {
         unsafe {
             // Prepare output buffer
@@ -64,6 +113,17 @@ impl Message {
         }
     }
 
+    /// Append FIX message bytes to given buffer.
+    ///
+    /// Unlike [`Message::to_fix_raw_bytes`], message string is generated only once
+    /// and no NUL terminator is added. Buffer is not cleared, so it can be reused
+    /// from one call to another to avoid new allocations.
+    pub fn write_fix_bytes(&self, buffer: &mut Vec<u8>) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixMessage_writeBytes(self.0, (buffer as *mut Vec<u8>).cast(), append_to_vec)
+        })
+    }
+
     /// Try reading underlying struct buffer as a FIX string.
     ///
     /// # Performances
@@ -212,6 +272,18 @@ impl FieldMap for Message {
         ffi_code_to_result(unsafe { FixMessage_setField(self.0, tag, fix_value.as_ptr()) })
     }
 
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        let mut len = 0;
+        unsafe { FixMessage_getFieldBytes(self.0, tag, &mut len) }
+            .map(|ptr| read_checked_bytes(ptr, len))
+    }
+
+    fn set_field_bytes(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixMessage_setFieldBytes(self.0, tag, value.as_ptr().cast(), value.len() as u64)
+        })
+    }
+
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError> {
         ffi_code_to_result(unsafe { FixMessage_removeField(self.0, tag) })
     }
@@ -224,6 +296,29 @@ impl FieldMap for Message {
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group> {
         unsafe { FixMessage_copyGroup(self.0, index, tag) }.map(Group)
     }
+
+    fn remove_group(&mut self, index: i32, tag: i32) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixMessage_removeGroup(self.0, index, tag) })
+    }
+
+    fn replace_group(&mut self, index: i32, group: &Group) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixMessage_replaceGroup(self.0, index, group.0) })
+    }
+
+    fn remove_all_groups(&mut self, tag: i32) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixMessage_removeAllGroups(self.0, tag) })
+    }
+
+    fn group_count(&self, tag: i32) -> usize {
+        let count = unsafe { FixMessage_getGroupCount(self.0, tag) };
+        count.try_into().unwrap_or_default()
+    }
+
+    fn iter_fields(&self) -> FieldIterator<'_> {
+        unsafe { FixMessage_getFieldIterator(self.0) }
+            .map(FieldIterator::new)
+            .expect("Fail to allocate FieldIterator")
+    }
 }
 
 impl Clone for Message {
@@ -252,6 +347,11 @@ impl Default for Message {
     }
 }
 
+// SAFETY: `FIX::Message` is a heap allocated tree of string fields, with no thread local state
+// and no pointer to engine objects, so its owner can use and drop it from any thread. Messages
+// borrowed from the engine in callbacks are only reachable through references.
+unsafe impl Send for Message {}
+
 impl Drop for Message {
     fn drop(&mut self) {
         unsafe { FixMessage_delete(self.0) }
@@ -0,0 +1,173 @@
+use std::fmt::{self, Write};
+
+use crate::{DataDictionary, FieldMap, Message};
+
+/// Source of field names and value descriptions used by [`MessageFormatter`].
+pub trait FieldDescriber {
+    /// Get field name from its tag number.
+    fn field_name(&self, tag: i32) -> Option<String>;
+
+    /// Get description of a field enum value (ex: `BUY` for `Side(54)=1`).
+    fn value_name(&self, tag: i32, value: &str) -> Option<String>;
+}
+
+impl FieldDescriber for DataDictionary {
+    fn field_name(&self, tag: i32) -> Option<String> {
+        self.get_field_name(tag)
+    }
+
+    fn value_name(&self, tag: i32, value: &str) -> Option<String> {
+        self.get_value_name(tag, value)
+    }
+}
+
+#[cfg(feature = "quickfix-spec-parser")]
+impl FieldDescriber for quickfix_spec_parser::FixSpec {
+    fn field_name(&self, tag: i32) -> Option<String> {
+        find_field_spec(self, tag).map(|spec| spec.name.clone())
+    }
+
+    fn value_name(&self, tag: i32, value: &str) -> Option<String> {
+        find_field_spec(self, tag)?
+            .values
+            .iter()
+            .find(|allowed| allowed.value == value)
+            .map(|allowed| allowed.description.clone())
+    }
+}
+
+#[cfg(feature = "quickfix-spec-parser")]
+fn find_field_spec(
+    spec: &quickfix_spec_parser::FixSpec,
+    tag: i32,
+) -> Option<&quickfix_spec_parser::FieldSpec> {
+    spec.field_spec(u32::try_from(tag).ok()?)
+}
+
+/// Layout used by [`MessageFormatter`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FormatLayout {
+    /// All fields on one line, separated with `|`. Repeating group entries are
+    /// wrapped in `[...]`.
+    #[default]
+    SingleLine,
+    /// One field per line. Repeating group entries are indented and start with `-`.
+    MultiLine,
+}
+
+/// Human readable message formatter.
+///
+/// Fields are printed as `Symbol(55)=IBM` and enum values with their description
+/// like `Side(54)=1 (BUY)`.
+#[derive(Debug)]
+pub struct MessageFormatter<'a, D: ?Sized> {
+    describer: &'a D,
+    layout: FormatLayout,
+}
+
+impl<'a, D: FieldDescriber + ?Sized> MessageFormatter<'a, D> {
+    /// Create a new formatter using single line layout.
+    pub fn new(describer: &'a D) -> Self {
+        Self {
+            describer,
+            layout: FormatLayout::default(),
+        }
+    }
+
+    /// Change output layout.
+    pub fn with_layout(mut self, layout: FormatLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Format header, body and trailer of a message.
+    pub fn format(&self, msg: &Message) -> String {
+        let mut output = String::new();
+        self.write_message(&mut output, msg)
+            .expect("Writing to a String cannot fail");
+        output
+    }
+
+    /// Format a field map (header, trailer, group, ...).
+    pub fn format_fields<T: FieldMap>(&self, fields: &T) -> String {
+        let mut output = String::new();
+        self.write_fields(&mut output, fields, false)
+            .expect("Writing to a String cannot fail");
+        output
+    }
+
+    fn write_message<W: Write>(&self, output: &mut W, msg: &Message) -> fmt::Result {
+        let mut has_content = msg.with_header(|x| self.write_fields(output, x, false))?;
+        has_content |= self.write_fields(output, msg, has_content)?;
+        msg.with_trailer(|x| self.write_fields(output, x, has_content))?;
+        Ok(())
+    }
+
+    /// Write fields and return `true` if something has been written.
+    fn write_fields<W: Write, T: FieldMap>(
+        &self,
+        output: &mut W,
+        fields: &T,
+        mut has_content: bool,
+    ) -> Result<bool, fmt::Error> {
+        let mut open_entries = 0;
+
+        let mut iter = fields.iter_fields();
+        while let Some((tag, value)) = iter.next() {
+            let depth = iter.depth();
+
+            match self.layout {
+                FormatLayout::SingleLine => {
+                    let entries = if iter.is_group_start() {
+                        depth - 1
+                    } else {
+                        depth
+                    };
+                    while open_entries > entries {
+                        output.write_char(']')?;
+                        open_entries -= 1;
+                    }
+                    if iter.is_group_start() {
+                        output.write_str(" [")?;
+                        open_entries += 1;
+                    } else if has_content {
+                        output.write_str(" | ")?;
+                    }
+                }
+                FormatLayout::MultiLine => {
+                    if has_content {
+                        output.write_char('\n')?;
+                    }
+                    if depth > 0 {
+                        output.write_str(&"    ".repeat(depth - 1))?;
+                        output.write_str(if iter.is_group_start() {
+                            "  - "
+                        } else {
+                            "    "
+                        })?;
+                    }
+                }
+            }
+
+            self.write_field(output, tag, &value)?;
+            has_content = true;
+        }
+
+        for _ in 0..open_entries {
+            output.write_char(']')?;
+        }
+
+        Ok(has_content)
+    }
+
+    fn write_field<W: Write>(&self, output: &mut W, tag: i32, value: &str) -> fmt::Result {
+        match self.describer.field_name(tag) {
+            Some(name) => write!(output, "{name}({tag})={value}")?,
+            None => write!(output, "{tag}={value}")?,
+        }
+        if let Some(description) = self.describer.value_name(tag, value) {
+            write!(output, " ({description})")?;
+        }
+        Ok(())
+    }
+}
@@ -36,6 +36,10 @@ impl FfiMessageStoreFactory for FileMessageStoreFactory {
     }
 }
 
+// SAFETY: C++ factory only keeps a copy of the settings and store path, and opens files when
+// a store is created, so nothing ties it to the thread it has been built on.
+unsafe impl Send for FileMessageStoreFactory {}
+
 impl Drop for FileMessageStoreFactory {
     fn drop(&mut self) {
         unsafe { FixMessageStoreFactory_delete(self.0) }
@@ -67,6 +71,9 @@ impl Default for MemoryMessageStoreFactory {
     }
 }
 
+// SAFETY: C++ factory has no state, each created store owns its messages.
+unsafe impl Send for MemoryMessageStoreFactory {}
+
 impl Drop for MemoryMessageStoreFactory {
     fn drop(&mut self) {
         unsafe { FixMessageStoreFactory_delete(self.0) }
@@ -100,6 +107,9 @@ impl Default for NullMessageStoreFactory {
     }
 }
 
+// SAFETY: C++ factory has no state and created stores do not keep any message.
+unsafe impl Send for NullMessageStoreFactory {}
+
 impl Drop for NullMessageStoreFactory {
     fn drop(&mut self) {
         unsafe { FixMessageStoreFactory_delete(self.0) }
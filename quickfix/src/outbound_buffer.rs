@@ -0,0 +1,279 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use crate::{session::send_to_target_mut, Message, QuickFixError, SessionId};
+
+type ExpiredCallback = Box<dyn Fn(Message, &SessionId) + Send + Sync>;
+
+/// Outcome of [`OutboundBuffer::send`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BufferedSend {
+    /// Message has been given to the session.
+    Sent,
+    /// Session is logged out, message will be sent after next logon.
+    Buffered,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LinkState {
+    LoggedOut,
+    /// Buffered messages are being sent, new ones still have to wait behind them.
+    Flushing,
+    /// Session is logged on, but a message could not be sent: new ones wait behind it until
+    /// flush is retried.
+    Stalled,
+    LoggedOn,
+}
+
+#[derive(Debug)]
+struct BufferedMessage {
+    msg: Message,
+    expires_at: Option<Instant>,
+}
+
+impl BufferedMessage {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
+#[derive(Debug)]
+struct SessionBuffer {
+    session_id: SessionId,
+    state: LinkState,
+    messages: VecDeque<BufferedMessage>,
+}
+
+/// Hold application messages while a session is logged out, and send them after next logon.
+///
+/// Buffer does not receive session events by itself: [`crate::ApplicationCallback::on_logon`] and
+/// [`crate::ApplicationCallback::on_logout`] must be forwarded to [`OutboundBuffer::on_logon`]
+/// and [`OutboundBuffer::on_logout`]. Sessions are considered logged out until their first
+/// forwarded logon.
+///
+/// Messages are sent in the order they have been given to the buffer. Messages given an expiry
+/// are dropped instead of being sent late, and handed to the callback set with
+/// [`OutboundBuffer::with_on_expired`].
+///
+/// If a message cannot be sent while session is logged on, it is kept with the following ones
+/// until [`OutboundBuffer::retry_flush`] succeeds or session logs on again.
+#[derive(Default)]
+pub struct OutboundBuffer {
+    sessions: Mutex<HashMap<String, SessionBuffer>>,
+    on_expired: Option<ExpiredCallback>,
+}
+
+impl OutboundBuffer {
+    /// Create a new empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set function called with messages dropped because they expired.
+    pub fn with_on_expired<F>(mut self, on_expired: F) -> Self
+    where
+        F: Fn(Message, &SessionId) + Send + Sync + 'static,
+    {
+        self.on_expired = Some(Box::new(on_expired));
+        self
+    }
+
+    /// Send message to target design in session ID, or buffer it until next logon.
+    ///
+    /// If the session fails to send the message, it is buffered with the following ones until
+    /// flush is retried, and the error is returned.
+    pub fn send(
+        &self,
+        msg: Message,
+        session_id: &SessionId,
+    ) -> Result<BufferedSend, QuickFixError> {
+        self.push(msg, session_id, None)
+    }
+
+    /// Same as [`Self::send`], but drop message if it is still buffered after `time_to_live`.
+    pub fn send_with_expiry(
+        &self,
+        msg: Message,
+        session_id: &SessionId,
+        time_to_live: Duration,
+    ) -> Result<BufferedSend, QuickFixError> {
+        self.push(msg, session_id, Some(Instant::now() + time_to_live))
+    }
+
+    /// Mark session as logged on and send its buffered messages.
+    ///
+    /// Returns how many messages have been sent. If a message cannot be sent, it is put back in
+    /// front of the buffer and the error is returned: see [`OutboundBuffer::retry_flush`].
+    pub fn on_logon(&self, session_id: &SessionId) -> Result<usize, QuickFixError> {
+        Self::session(&mut self.lock(), session_id).state = LinkState::Flushing;
+        self.flush(session_id)
+    }
+
+    /// Send buffered messages of a logged on session, after one failed to be sent.
+    ///
+    /// Returns how many messages have been sent, nothing is sent if session is logged out or
+    /// no message failed.
+    pub fn retry_flush(&self, session_id: &SessionId) -> Result<usize, QuickFixError> {
+        {
+            let mut sessions = self.lock();
+            let session = Self::session(&mut sessions, session_id);
+            if session.state != LinkState::Stalled {
+                return Ok(0);
+            }
+            session.state = LinkState::Flushing;
+        }
+        self.flush(session_id)
+    }
+
+    /// Mark session as logged out: next messages are buffered.
+    pub fn on_logout(&self, session_id: &SessionId) {
+        Self::session(&mut self.lock(), session_id).state = LinkState::LoggedOut;
+    }
+
+    /// Drop expired messages of every session.
+    ///
+    /// Expired messages are also dropped on logon, this is only required to be notified early.
+    /// Returns how many messages have been dropped.
+    pub fn purge_expired(&self) -> usize {
+        let now = Instant::now();
+        let mut expired = Vec::new();
+        for session in self.lock().values_mut() {
+            let (keep, drop) = session
+                .messages
+                .drain(..)
+                .partition(|buffered| !buffered.is_expired(now));
+            session.messages = keep;
+            expired.extend(
+                drop.into_iter()
+                    .map(|buffered: BufferedMessage| (buffered.msg, session.session_id.clone())),
+            );
+        }
+
+        let count = expired.len();
+        for (msg, session_id) in expired {
+            self.expire(msg, &session_id);
+        }
+        count
+    }
+
+    /// Check if messages to a session are sent straight away, without being buffered.
+    pub fn is_logged_on(&self, session_id: &SessionId) -> bool {
+        self.lock()
+            .get(&session_id.to_repr())
+            .is_some_and(|session| session.state == LinkState::LoggedOn)
+    }
+
+    /// Get number of messages waiting for a session logon.
+    pub fn buffered_count(&self, session_id: &SessionId) -> usize {
+        self.lock()
+            .get(&session_id.to_repr())
+            .map_or(0, |session| session.messages.len())
+    }
+
+    /// Send buffered messages while session is flushing.
+    fn flush(&self, session_id: &SessionId) -> Result<usize, QuickFixError> {
+        let mut count = 0;
+        loop {
+            let buffered = {
+                let mut sessions = self.lock();
+                let session = Self::session(&mut sessions, session_id);
+                if session.state != LinkState::Flushing {
+                    // Logged out again while flushing.
+                    return Ok(count);
+                }
+                match session.messages.pop_front() {
+                    Some(buffered) => buffered,
+                    None => {
+                        session.state = LinkState::LoggedOn;
+                        return Ok(count);
+                    }
+                }
+            };
+
+            if buffered.is_expired(Instant::now()) {
+                self.expire(buffered.msg, session_id);
+                continue;
+            }
+            let mut buffered = buffered;
+            if let Err(err) = send_to_target_mut(&mut buffered.msg, session_id) {
+                let mut sessions = self.lock();
+                let session = Self::session(&mut sessions, session_id);
+                if session.state == LinkState::Flushing {
+                    session.state = LinkState::Stalled;
+                }
+                session.messages.push_front(buffered);
+                return Err(err);
+            }
+            count += 1;
+        }
+    }
+
+    fn push(
+        &self,
+        mut msg: Message,
+        session_id: &SessionId,
+        expires_at: Option<Instant>,
+    ) -> Result<BufferedSend, QuickFixError> {
+        {
+            let mut sessions = self.lock();
+            let session = Self::session(&mut sessions, session_id);
+            if session.state != LinkState::LoggedOn {
+                session
+                    .messages
+                    .push_back(BufferedMessage { msg, expires_at });
+                return Ok(BufferedSend::Buffered);
+            }
+        }
+
+        // Lock is released here: sending may call application callbacks using this buffer.
+        if let Err(err) = send_to_target_mut(&mut msg, session_id) {
+            let mut sessions = self.lock();
+            let session = Self::session(&mut sessions, session_id);
+            if session.state == LinkState::LoggedOn {
+                session.state = LinkState::Stalled;
+            }
+            session
+                .messages
+                .push_back(BufferedMessage { msg, expires_at });
+            return Err(err);
+        }
+        Ok(BufferedSend::Sent)
+    }
+
+    fn expire(&self, msg: Message, session_id: &SessionId) {
+        if let Some(on_expired) = &self.on_expired {
+            on_expired(msg, session_id);
+        }
+    }
+
+    fn session<'a>(
+        sessions: &'a mut HashMap<String, SessionBuffer>,
+        session_id: &SessionId,
+    ) -> &'a mut SessionBuffer {
+        sessions
+            .entry(session_id.to_repr())
+            .or_insert_with(|| SessionBuffer {
+                session_id: session_id.clone(),
+                state: LinkState::LoggedOut,
+                messages: VecDeque::new(),
+            })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, SessionBuffer>> {
+        // Buffers stay consistent even if a thread panicked while holding the lock.
+        self.sessions.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl fmt::Debug for OutboundBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutboundBuffer")
+            .field("sessions", &self.sessions)
+            .field("on_expired", &self.on_expired.is_some())
+            .finish()
+    }
+}
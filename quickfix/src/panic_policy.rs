@@ -0,0 +1,196 @@
+use std::{
+    any::Any,
+    ffi,
+    panic::{catch_unwind, AssertUnwindSafe},
+    process,
+    ptr::NonNull,
+    sync::Arc,
+};
+
+use crate::{Session, SessionId};
+
+/// What to do when user code panics inside a callback.
+///
+/// Panics never cross the FFI boundary: they are always caught first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PanicPolicy {
+    /// Let FIX engine continue its workflow, as if callback returned normally.
+    #[default]
+    Ignore,
+    /// Like [`PanicPolicy::Ignore`], but report the panic using `log` crate or stderr.
+    Log,
+    /// Log the panic and reject the message being handled.
+    ///
+    /// Outgoing application messages and skippable admin messages are not sent, resent ones are
+    /// replaced by a gap fill, received logons are rejected, other received admin messages get
+    /// a `Reject` and received application messages a `BusinessMessageReject`. Other callbacks
+    /// just log the panic.
+    Reject,
+    /// Log the panic and logout the session.
+    ///
+    /// Session is disabled until [`Session::logon`] is called. Current message is processed
+    /// as if callback returned normally.
+    Disconnect,
+    /// Log the panic and abort the process.
+    Abort,
+}
+
+/// Panic caught from a callback.
+#[derive(Debug)]
+pub struct CallbackPanic<'a> {
+    /// Name of the panicking callback, like `on_msg_from_app`.
+    pub callback: &'static str,
+    /// Value given to `panic!`.
+    pub payload: &'a (dyn Any + Send),
+    /// ID of the session the callback was called for, if any.
+    pub session_id: Option<&'a SessionId>,
+}
+
+impl CallbackPanic<'_> {
+    /// Get panic message, when panic payload is a string.
+    pub fn message(&self) -> Option<&str> {
+        if let Some(text) = self.payload.downcast_ref::<&str>() {
+            Some(text)
+        } else {
+            self.payload.downcast_ref::<String>().map(String::as_str)
+        }
+    }
+
+    fn report(&self) {
+        let session_id = self.session_id.map(SessionId::to_repr);
+        let message = self.message().unwrap_or("Box<dyn Any>");
+
+        #[cfg(feature = "log")]
+        log::error!(
+            "FIX: Callback `{}` panicked: {session_id:?}: {message}",
+            self.callback
+        );
+        #[cfg(not(feature = "log"))]
+        {
+            use std::io::Write;
+            let _ = writeln!(
+                std::io::stderr(),
+                "FIX: Callback `{}` panicked: {session_id:?}: {message}",
+                self.callback
+            );
+        }
+    }
+}
+
+type PanicHook = dyn Fn(&CallbackPanic<'_>) + Send + Sync;
+
+/// State shared with C++ code, which is given back to each callback.
+pub(crate) struct CallbackContext<C> {
+    callbacks: *const C,
+    _owned: Option<Arc<C>>,
+    policy: PanicPolicy,
+    hook: Option<Box<PanicHook>>,
+}
+
+impl<C> CallbackContext<C> {
+    /// Allocate context borrowing callbacks.
+    ///
+    /// Caller must ensure callbacks outlive context.
+    pub(crate) fn new_borrowed(callbacks: &C) -> NonNull<Self> {
+        Self::allocate(callbacks, None)
+    }
+
+    /// Allocate context owning callbacks.
+    pub(crate) fn new_owned(callbacks: Arc<C>) -> NonNull<Self> {
+        Self::allocate(Arc::as_ptr(&callbacks), Some(callbacks))
+    }
+
+    fn allocate(callbacks: *const C, owned: Option<Arc<C>>) -> NonNull<Self> {
+        let context = Box::new(Self {
+            callbacks,
+            _owned: owned,
+            policy: PanicPolicy::default(),
+            hook: None,
+        });
+        NonNull::from(Box::leak(context))
+    }
+
+    /// Release context allocated by `new_borrowed` or `new_owned`.
+    ///
+    /// Pointer must not be used afterward.
+    pub(crate) unsafe fn release(context: NonNull<Self>) {
+        drop(Box::from_raw(context.as_ptr()));
+    }
+
+    /// Update panic policy.
+    ///
+    /// No callback must be running.
+    pub(crate) unsafe fn set_policy(context: NonNull<Self>, policy: PanicPolicy) {
+        (*context.as_ptr()).policy = policy;
+    }
+
+    /// Update panic hook.
+    ///
+    /// No callback must be running.
+    pub(crate) unsafe fn set_hook(context: NonNull<Self>, hook: Box<PanicHook>) {
+        (*context.as_ptr()).hook = Some(hook);
+    }
+
+    /// Get context back from the pointer given to C++ code.
+    pub(crate) unsafe fn from_ffi<'a>(data: *const ffi::c_void) -> &'a Self {
+        &*(data as *const Self)
+    }
+
+    /// Run callback, and apply panic policy if it panics.
+    ///
+    /// Return `None` on panic.
+    pub(crate) fn call<T>(
+        &self,
+        callback: &'static str,
+        session_id: Option<&SessionId>,
+        f: impl FnOnce(&C) -> T,
+    ) -> Option<T> {
+        // SAFETY: callbacks outlive context.
+        let callbacks = unsafe { &*self.callbacks };
+
+        match catch_unwind(AssertUnwindSafe(|| f(callbacks))) {
+            Ok(output) => Some(output),
+            Err(payload) => {
+                self.on_panic(CallbackPanic {
+                    callback,
+                    payload: &*payload,
+                    session_id,
+                });
+                None
+            }
+        }
+    }
+
+    /// Get the callback code to return after a panic, when message should be rejected.
+    pub(crate) fn reject_code(&self, code: i8) -> i8 {
+        match self.policy {
+            PanicPolicy::Reject => code,
+            _ => quickfix_ffi::CALLBACK_OK,
+        }
+    }
+
+    fn on_panic(&self, panic: CallbackPanic<'_>) {
+        if let Some(hook) = &self.hook {
+            // Nothing more can be done if the hook panics too.
+            let _ = catch_unwind(AssertUnwindSafe(|| hook(&panic)));
+        }
+
+        match self.policy {
+            PanicPolicy::Ignore => {}
+            PanicPolicy::Log | PanicPolicy::Reject => panic.report(),
+            PanicPolicy::Disconnect => {
+                panic.report();
+                if let Some(session_id) = panic.session_id {
+                    // SAFETY: session is alive, since it is calling us back.
+                    if let Ok(mut session) = unsafe { Session::lookup(session_id) } {
+                        let _ = session.logout();
+                    }
+                }
+            }
+            PanicPolicy::Abort => {
+                panic.report();
+                process::abort();
+            }
+        }
+    }
+}
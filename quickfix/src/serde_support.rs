@@ -0,0 +1,574 @@
+use std::{fmt, iter::Peekable, marker::PhantomData};
+
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{DataDictionary, FieldIterator, FieldMap, Group, Header, Message, Trailer};
+
+/// Serialize FIX object using field names from a data dictionary instead of tag numbers.
+///
+/// Tags that are unknown from the dictionary are still serialized as numbers.
+///
+/// ```no_run
+/// # use quickfix::*;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let dictionary = DataDictionary::try_from_path("FIX44.xml")?;
+/// let msg = Message::try_from_text("...")?;
+///
+/// let json = serde_json::to_string(&WithFieldNames::new(&msg, &dictionary))?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WithFieldNames<'a, T> {
+    value: &'a T,
+    dictionary: &'a DataDictionary,
+}
+
+impl<'a, T> WithFieldNames<'a, T> {
+    /// Create a new struct from object to serialize and dictionary to use.
+    pub fn new(value: &'a T, dictionary: &'a DataDictionary) -> Self {
+        Self { value, dictionary }
+    }
+}
+
+/// Deserialize FIX object accepting field names from a data dictionary as well as tag numbers.
+///
+/// ```no_run
+/// # use quickfix::*;
+/// # use serde::de::DeserializeSeed;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let dictionary = DataDictionary::try_from_path("FIX44.xml")?;
+/// let json = r#"{"header": {"MsgType": "B"}, "body": {"Headline": "Hello"}}"#;
+///
+/// let msg = FieldNamesSeed::<Message>::new(&dictionary)
+///     .deserialize(&mut serde_json::Deserializer::from_str(json))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct FieldNamesSeed<'a, T> {
+    dictionary: &'a DataDictionary,
+    phantom_value: PhantomData<T>,
+}
+
+impl<'a, T> FieldNamesSeed<'a, T> {
+    /// Create a new struct from dictionary to use.
+    pub fn new(dictionary: &'a DataDictionary) -> Self {
+        Self {
+            dictionary,
+            phantom_value: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for FieldNamesSeed<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldNamesSeed")
+            .field("dictionary", &self.dictionary)
+            .finish()
+    }
+}
+
+// Serialization.
+
+/// Field value of a field map once rebuilt as a tree.
+enum FieldNode {
+    Value(String),
+    Group(Vec<Vec<(i32, FieldNode)>>),
+}
+
+/// Rebuild repeating groups tree from flat field iterator.
+fn collect_nodes(iter: FieldIterator<'_>) -> Vec<(i32, FieldNode)> {
+    struct Item {
+        tag: i32,
+        value: String,
+        depth: usize,
+        group_start: bool,
+    }
+
+    fn collect_level<I: Iterator<Item = Item>>(
+        items: &mut Peekable<I>,
+        depth: usize,
+    ) -> Vec<(i32, FieldNode)> {
+        let mut output = Vec::new();
+
+        while let Some(item) = items.peek() {
+            if item.depth != depth || (item.group_start && !output.is_empty()) {
+                break;
+            }
+            let item = items.next().expect("Item has been peeked");
+
+            if items.peek().is_some_and(|next| next.depth > depth) {
+                let mut entries = Vec::new();
+                while items.peek().is_some_and(|next| next.depth > depth) {
+                    entries.push(collect_level(items, depth + 1));
+                }
+                output.push((item.tag, FieldNode::Group(entries)));
+            } else {
+                output.push((item.tag, FieldNode::Value(item.value)));
+            }
+        }
+
+        output
+    }
+
+    let mut iter = iter;
+    let mut items = Vec::new();
+    while let Some((tag, value)) = iter.next() {
+        items.push(Item {
+            tag,
+            value,
+            depth: iter.depth(),
+            group_start: iter.is_group_start(),
+        });
+    }
+
+    collect_level(&mut items.into_iter().peekable(), 0)
+}
+
+struct NodesRef<'a> {
+    nodes: &'a [(i32, FieldNode)],
+    dictionary: Option<&'a DataDictionary>,
+}
+
+impl Serialize for NodesRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.nodes.len()))?;
+        for (tag, node) in self.nodes {
+            match self.dictionary.and_then(|dict| dict.get_field_name(*tag)) {
+                Some(name) => map.serialize_key(&name)?,
+                None => map.serialize_key(tag)?,
+            }
+            match node {
+                FieldNode::Value(value) => map.serialize_value(value)?,
+                FieldNode::Group(entries) => map.serialize_value(&EntriesRef {
+                    entries,
+                    dictionary: self.dictionary,
+                })?,
+            }
+        }
+        map.end()
+    }
+}
+
+struct EntriesRef<'a> {
+    entries: &'a [Vec<(i32, FieldNode)>],
+    dictionary: Option<&'a DataDictionary>,
+}
+
+impl Serialize for EntriesRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.entries.iter().map(|nodes| NodesRef {
+            nodes,
+            dictionary: self.dictionary,
+        }))
+    }
+}
+
+trait SerializeFix {
+    fn serialize_fix<S: Serializer>(
+        &self,
+        dictionary: Option<&DataDictionary>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+fn serialize_field_map<T: FieldMap, S: Serializer>(
+    value: &T,
+    dictionary: Option<&DataDictionary>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    NodesRef {
+        nodes: &collect_nodes(value.iter_fields()),
+        dictionary,
+    }
+    .serialize(serializer)
+}
+
+impl SerializeFix for Header {
+    fn serialize_fix<S: Serializer>(
+        &self,
+        dictionary: Option<&DataDictionary>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_field_map(self, dictionary, serializer)
+    }
+}
+
+impl SerializeFix for Trailer {
+    fn serialize_fix<S: Serializer>(
+        &self,
+        dictionary: Option<&DataDictionary>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_field_map(self, dictionary, serializer)
+    }
+}
+
+impl SerializeFix for Group {
+    fn serialize_fix<S: Serializer>(
+        &self,
+        dictionary: Option<&DataDictionary>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let fields = collect_nodes(self.iter_fields());
+
+        let mut state = serializer.serialize_struct("Group", 3)?;
+        state.serialize_field("field_id", &self.field_id())?;
+        state.serialize_field("delim", &self.delim())?;
+        state.serialize_field(
+            "fields",
+            &NodesRef {
+                nodes: &fields,
+                dictionary,
+            },
+        )?;
+        state.end()
+    }
+}
+
+impl SerializeFix for Message {
+    fn serialize_fix<S: Serializer>(
+        &self,
+        dictionary: Option<&DataDictionary>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let header = self.with_header(|header| collect_nodes(header.iter_fields()));
+        let body = collect_nodes(self.iter_fields());
+        let trailer = self.with_trailer(|trailer| collect_nodes(trailer.iter_fields()));
+
+        let mut state = serializer.serialize_struct("Message", 3)?;
+        for (key, nodes) in [("header", &header), ("body", &body), ("trailer", &trailer)] {
+            state.serialize_field(key, &NodesRef { nodes, dictionary })?;
+        }
+        state.end()
+    }
+}
+
+// Deserialization.
+
+/// Field value of a field map before being inserted into C++ object.
+enum FieldInput {
+    Value(String),
+    Group(Vec<Vec<(i32, FieldInput)>>),
+}
+
+fn apply_fields<T: FieldMap, E: de::Error>(
+    target: &mut T,
+    fields: Vec<(i32, FieldInput)>,
+) -> Result<(), E> {
+    for (tag, input) in fields {
+        match input {
+            FieldInput::Value(value) => target.set_field(tag, value).map_err(E::custom)?,
+            FieldInput::Group(entries) => {
+                for entry in entries {
+                    let orders: Vec<_> = entry.iter().map(|(tag, _)| *tag).collect();
+                    let Some(delim) = orders.first().copied() else {
+                        continue;
+                    };
+
+                    let mut group =
+                        Group::try_with_orders(tag, delim, &orders).map_err(E::custom)?;
+                    apply_fields(&mut group, entry)?;
+                    target.add_group(&group).map_err(E::custom)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+struct FieldsSeed<'a> {
+    dictionary: Option<&'a DataDictionary>,
+}
+
+impl FieldsSeed<'_> {
+    fn parse_tag<E: de::Error>(&self, key: &str) -> Result<i32, E> {
+        if let Ok(tag) = key.parse() {
+            return Ok(tag);
+        }
+        self.dictionary
+            .and_then(|dict| dict.get_field_tag(key))
+            .ok_or_else(|| E::custom(format!("unknown FIX field: {key}")))
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for FieldsSeed<'_> {
+    type Value = Vec<(i32, FieldInput)>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldsSeed<'_> {
+    type Value = Vec<(i32, FieldInput)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of FIX fields")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut output = Vec::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some(key) = map.next_key::<TagKey>()? {
+            let tag = match key {
+                TagKey::Number(tag) => tag,
+                TagKey::Name(name) => self.parse_tag(&name)?,
+            };
+            output.push((tag, map.next_value_seed(FieldInputSeed(self))?));
+        }
+        Ok(output)
+    }
+}
+
+enum TagKey {
+    Number(i32),
+    Name(String),
+}
+
+impl<'de> Deserialize<'de> for TagKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TagKeyVisitor;
+
+        impl Visitor<'_> for TagKeyVisitor {
+            type Value = TagKey;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a FIX tag number or field name")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                i32::try_from(value).map(TagKey::Number).map_err(E::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                i32::try_from(value).map(TagKey::Number).map_err(E::custom)
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(TagKey::Name(value.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(TagKeyVisitor)
+    }
+}
+
+struct FieldInputSeed<'a>(FieldsSeed<'a>);
+
+impl<'de> DeserializeSeed<'de> for FieldInputSeed<'_> {
+    type Value = FieldInput;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldInputSeed<'_> {
+    type Value = FieldInput;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a FIX field value or a list of repeating group entries")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(FieldInput::Value(if value { "Y" } else { "N" }.to_string()))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(FieldInput::Value(value.to_string()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(FieldInput::Value(value.to_string()))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(FieldInput::Value(value.to_string()))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(FieldInput::Value(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(FieldInput::Value(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(entry) = seq.next_element_seed(self.0)? {
+            entries.push(entry);
+        }
+        Ok(FieldInput::Group(entries))
+    }
+}
+
+trait DeserializeFix: Sized {
+    fn deserialize_fix<'de, D: Deserializer<'de>>(
+        dictionary: Option<&DataDictionary>,
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+}
+
+impl DeserializeFix for Header {
+    fn deserialize_fix<'de, D: Deserializer<'de>>(
+        dictionary: Option<&DataDictionary>,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let fields = FieldsSeed { dictionary }.deserialize(deserializer)?;
+        let mut output = Header::new();
+        apply_fields(&mut output, fields)?;
+        Ok(output)
+    }
+}
+
+impl DeserializeFix for Trailer {
+    fn deserialize_fix<'de, D: Deserializer<'de>>(
+        dictionary: Option<&DataDictionary>,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let fields = FieldsSeed { dictionary }.deserialize(deserializer)?;
+        let mut output = Trailer::new();
+        apply_fields(&mut output, fields)?;
+        Ok(output)
+    }
+}
+
+impl DeserializeFix for Group {
+    fn deserialize_fix<'de, D: Deserializer<'de>>(
+        dictionary: Option<&DataDictionary>,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct GroupVisitor<'a>(FieldsSeed<'a>);
+
+        impl<'de> Visitor<'de> for GroupVisitor<'_> {
+            type Value = Group;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a FIX repeating group")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut field_id = None;
+                let mut delim = None;
+                let mut fields = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "field_id" => field_id = Some(map.next_value()?),
+                        "delim" => delim = Some(map.next_value()?),
+                        "fields" => fields = Some(map.next_value_seed(self.0)?),
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                let field_id = field_id.ok_or_else(|| de::Error::missing_field("field_id"))?;
+                let delim = delim.ok_or_else(|| de::Error::missing_field("delim"))?;
+                let fields: Vec<(i32, FieldInput)> = fields.unwrap_or_default();
+
+                let orders: Vec<_> = fields.iter().map(|(tag, _)| *tag).collect();
+                let mut output =
+                    Group::try_with_orders(field_id, delim, &orders).map_err(de::Error::custom)?;
+                apply_fields(&mut output, fields)?;
+                Ok(output)
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Group",
+            &["field_id", "delim", "fields"],
+            GroupVisitor(FieldsSeed { dictionary }),
+        )
+    }
+}
+
+impl DeserializeFix for Message {
+    fn deserialize_fix<'de, D: Deserializer<'de>>(
+        dictionary: Option<&DataDictionary>,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct MessageVisitor<'a>(FieldsSeed<'a>);
+
+        impl<'de> Visitor<'de> for MessageVisitor<'_> {
+            type Value = Message;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a FIX message")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut output = Message::new();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    let fields = match key.as_str() {
+                        "header" | "body" | "trailer" => map.next_value_seed(self.0)?,
+                        _ => {
+                            map.next_value::<de::IgnoredAny>()?;
+                            continue;
+                        }
+                    };
+
+                    match key.as_str() {
+                        "header" => output.with_header_mut(|x| apply_fields(x, fields))?,
+                        "trailer" => output.with_trailer_mut(|x| apply_fields(x, fields))?,
+                        _ => apply_fields(&mut output, fields)?,
+                    }
+                }
+
+                Ok(output)
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "Message",
+            &["header", "body", "trailer"],
+            MessageVisitor(FieldsSeed { dictionary }),
+        )
+    }
+}
+
+// Public trait implementations.
+
+macro_rules! impl_serde {
+    ($($ty:ty),*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.serialize_fix(None, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    Self::deserialize_fix(None, deserializer)
+                }
+            }
+
+            impl Serialize for WithFieldNames<'_, $ty> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.value.serialize_fix(Some(self.dictionary), serializer)
+                }
+            }
+
+            impl<'de> DeserializeSeed<'de> for FieldNamesSeed<'_, $ty> {
+                type Value = $ty;
+
+                fn deserialize<D: Deserializer<'de>>(
+                    self,
+                    deserializer: D,
+                ) -> Result<Self::Value, D::Error> {
+                    <$ty>::deserialize_fix(Some(self.dictionary), deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde!(Message, Header, Trailer, Group);
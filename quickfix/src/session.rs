@@ -1,21 +1,72 @@
-use std::{fmt, marker::PhantomData};
+use std::{ffi::CString, fmt, marker::PhantomData};
 
 use quickfix_ffi::{
-    FixSession_isLoggedOn, FixSession_logon, FixSession_logout, FixSession_lookup,
-    FixSession_reset, FixSession_send, FixSession_sendToTarget, FixSession_t,
+    FixSession_getCreationTime, FixSession_getExpectedSenderNum, FixSession_getExpectedTargetNum,
+    FixSession_getHeartBtInt, FixSession_getSessionID, FixSession_isEnabled,
+    FixSession_isInitiator, FixSession_isLoggedOn, FixSession_isSessionTime, FixSession_logon,
+    FixSession_logout, FixSession_lookup, FixSession_receivedLogon, FixSession_refresh,
+    FixSession_reset, FixSession_send, FixSession_sendResendRequest, FixSession_sendSequenceReset,
+    FixSession_sendTestRequest, FixSession_sendToTarget, FixSession_sentLogon,
+    FixSession_setNextSenderMsgSeqNum, FixSession_setNextTargetMsgSeqNum, FixSession_t,
 };
 
 use crate::{
-    utils::{ffi_code_to_bool, ffi_code_to_result},
-    Message, QuickFixError, SessionId,
+    utils::{ffi_code_to_bool, ffi_code_to_result, read_checked_cstr},
+    FromFixValue, Message, QuickFixError, SessionId, UtcTimestamp,
 };
 
 /// Send message to target design in session ID.
-pub fn send_to_target(msg: Message, session_id: &SessionId) -> Result<(), QuickFixError> {
+pub fn send_to_target(mut msg: Message, session_id: &SessionId) -> Result<(), QuickFixError> {
     // NOTE: Message may be changed by real library. Just consume it so nothing will leak to rust code.
+    send_to_target_mut(&mut msg, session_id)
+}
+
+/// Send message without consuming it, so it can be sent again when sending fails.
+pub(crate) fn send_to_target_mut(
+    msg: &mut Message,
+    session_id: &SessionId,
+) -> Result<(), QuickFixError> {
     ffi_code_to_result(unsafe { FixSession_sendToTarget(msg.0, session_id.0) })
 }
 
+/// Snapshot of a session state.
+///
+/// Use [`Session::status`] or [`crate::SessionContainer::session_status`] to build it.
+#[derive(Debug, Clone)]
+pub struct SessionStatus {
+    /// ID of the session.
+    pub session_id: SessionId,
+    /// Session is enabled and will try to logon.
+    pub is_enabled: bool,
+    /// Current time is in the configured session schedule.
+    pub is_session_time: bool,
+    /// Logon message has been sent.
+    pub sent_logon: bool,
+    /// Logon message has been received.
+    pub received_logon: bool,
+    /// Session is an initiator (`false` for acceptor).
+    pub is_initiator: bool,
+    /// Negotiated heartbeat interval (in seconds).
+    ///
+    /// Initiators use the one from their settings, acceptors the one received in counterparty
+    /// logon, so it is `None` until then.
+    pub heartbeat_interval: Option<i32>,
+    /// Session creation time as stored in the message store.
+    pub creation_time: UtcTimestamp,
+}
+
+impl SessionStatus {
+    /// Check if both logon messages have been exchanged.
+    pub fn is_logged_on(&self) -> bool {
+        self.sent_logon && self.received_logon
+    }
+
+    /// Check if session is an acceptor.
+    pub fn is_acceptor(&self) -> bool {
+        !self.is_initiator
+    }
+}
+
 /// FIX Session.
 pub struct Session<'a> {
     pub(crate) inner: FixSession_t,
@@ -59,6 +110,48 @@ impl Session<'_> {
         ffi_code_to_bool(unsafe { FixSession_send(self.inner, msg.0) })
     }
 
+    /// Ask counterparty to resend messages from `begin` to `end` sequence numbers, included.
+    ///
+    /// Use `0` as `end` to ask for all messages following `begin`.
+    pub fn send_resend_request(&mut self, begin: u64, end: u64) -> Result<bool, QuickFixError> {
+        if begin == 0 || (end != 0 && end < begin) {
+            return Err(QuickFixError::invalid_argument(format!(
+                "Invalid resend range: {begin} to {end}"
+            )));
+        }
+        ffi_code_to_bool(unsafe { FixSession_sendResendRequest(self.inner, begin, end) })
+    }
+
+    /// Send a `SequenceReset` so counterparty expects `new_seq_num` as next message sequence number.
+    ///
+    /// With `gap_fill`, message takes the next sequence number and tells counterparty the
+    /// following ones are skipped, otherwise its sequence number is ignored.
+    /// Once sent, next sender sequence number is set to `new_seq_num`, and skipped messages are
+    /// gap filled if counterparty asks to resend them. It is left unchanged if message is not sent.
+    pub fn send_sequence_reset(
+        &mut self,
+        new_seq_num: u64,
+        gap_fill: bool,
+    ) -> Result<bool, QuickFixError> {
+        let next_seq_num = self.next_sender_msg_seq_num()?;
+        if new_seq_num <= next_seq_num {
+            return Err(QuickFixError::invalid_argument(format!(
+                "New sequence number {new_seq_num} must be greater than {next_seq_num}"
+            )));
+        }
+        ffi_code_to_bool(unsafe {
+            FixSession_sendSequenceReset(self.inner, new_seq_num, gap_fill as i8)
+        })
+    }
+
+    /// Send a `TestRequest`, counterparty should answer with a `Heartbeat` having same `TestReqID`.
+    ///
+    /// Use [`crate::HeartbeatTracker`] to wait for it.
+    pub fn send_test_request(&mut self, test_req_id: &str) -> Result<bool, QuickFixError> {
+        let c_test_req_id = CString::new(test_req_id)?;
+        ffi_code_to_bool(unsafe { FixSession_sendTestRequest(self.inner, c_test_req_id.as_ptr()) })
+    }
+
     /// Reset session by sending a logout & disconnecting, but still keeping the session enabled,
     /// so that logon is retried.
     pub fn reset(&mut self) -> Result<(), QuickFixError> {
@@ -69,6 +162,107 @@ impl Session<'_> {
     pub fn logon(&mut self) -> Result<(), QuickFixError> {
         ffi_code_to_result(unsafe { FixSession_logon(self.inner) })
     }
+
+    /// Get sequence number of the next message to send.
+    pub fn next_sender_msg_seq_num(&mut self) -> Result<u64, QuickFixError> {
+        let mut num = 0;
+        ffi_code_to_result(unsafe { FixSession_getExpectedSenderNum(self.inner, &mut num) })?;
+        Ok(num)
+    }
+
+    /// Set sequence number of the next message to send.
+    pub fn set_next_sender_msg_seq_num(&mut self, num: u64) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixSession_setNextSenderMsgSeqNum(self.inner, num) })
+    }
+
+    /// Get expected sequence number of the next message to receive.
+    pub fn next_target_msg_seq_num(&mut self) -> Result<u64, QuickFixError> {
+        let mut num = 0;
+        ffi_code_to_result(unsafe { FixSession_getExpectedTargetNum(self.inner, &mut num) })?;
+        Ok(num)
+    }
+
+    /// Set expected sequence number of the next message to receive.
+    pub fn set_next_target_msg_seq_num(&mut self, num: u64) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixSession_setNextTargetMsgSeqNum(self.inner, num) })
+    }
+
+    /// Reload session state (sequence numbers, creation time, ...) from message store.
+    pub fn refresh(&mut self) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixSession_refresh(self.inner) })
+    }
+
+    /// Check if session is enabled.
+    pub fn is_enabled(&mut self) -> Result<bool, QuickFixError> {
+        ffi_code_to_bool(unsafe { FixSession_isEnabled(self.inner) })
+    }
+
+    /// Check if current time is in the session schedule.
+    pub fn is_session_time(&mut self) -> Result<bool, QuickFixError> {
+        ffi_code_to_bool(unsafe { FixSession_isSessionTime(self.inner) })
+    }
+
+    /// Check if logon message has been sent.
+    pub fn sent_logon(&mut self) -> Result<bool, QuickFixError> {
+        ffi_code_to_bool(unsafe { FixSession_sentLogon(self.inner) })
+    }
+
+    /// Check if logon message has been received.
+    pub fn received_logon(&mut self) -> Result<bool, QuickFixError> {
+        ffi_code_to_bool(unsafe { FixSession_receivedLogon(self.inner) })
+    }
+
+    /// Check if session is an initiator.
+    pub fn is_initiator(&mut self) -> Result<bool, QuickFixError> {
+        ffi_code_to_bool(unsafe { FixSession_isInitiator(self.inner) })
+    }
+
+    /// Check if session is an acceptor.
+    pub fn is_acceptor(&mut self) -> Result<bool, QuickFixError> {
+        self.is_initiator().map(|x| !x)
+    }
+
+    /// Get negotiated heartbeat interval (in seconds), `None` if not known yet.
+    pub fn heartbeat_interval(&mut self) -> Result<Option<i32>, QuickFixError> {
+        let mut value = 0;
+        ffi_code_to_result(unsafe { FixSession_getHeartBtInt(self.inner, &mut value) })?;
+        Ok((value > 0).then_some(value))
+    }
+
+    /// Get ID of the session.
+    ///
+    /// # Panic
+    ///
+    /// When memory allocation fail in C++ library.
+    pub fn session_id(&mut self) -> SessionId {
+        unsafe { FixSession_getSessionID(self.inner) }
+            .map(SessionId)
+            .expect("Fail to allocate SessionId")
+    }
+
+    /// Get session creation time from message store.
+    pub fn creation_time(&mut self) -> Result<UtcTimestamp, QuickFixError> {
+        let value = unsafe { FixSession_getCreationTime(self.inner) }
+            .map(read_checked_cstr)
+            .ok_or_else(QuickFixError::from_last_error)?;
+        UtcTimestamp::from_fix_value(&value).ok_or_else(|| {
+            QuickFixError::invalid_argument(format!("Invalid creation time: {value}"))
+        })
+    }
+
+    /// Take a snapshot of the session state.
+    pub fn status(&mut self) -> Result<SessionStatus, QuickFixError> {
+        Ok(SessionStatus {
+            session_id: self.session_id(),
+            is_enabled: self.is_enabled()?,
+            is_session_time: self.is_session_time()?,
+            sent_logon: self.sent_logon()?,
+            received_logon: self.received_logon()?,
+            is_initiator: self.is_initiator()?,
+            heartbeat_interval: self.heartbeat_interval()?,
+            creation_time: self.creation_time()?,
+        })
+    }
 }
 
 impl fmt::Debug for Session<'_> {
@@ -87,6 +87,11 @@ impl fmt::Debug for SessionId {
     }
 }
 
+// SAFETY: `FIX::SessionID` is an immutable value made of a few strings, with no thread local
+// state. Session IDs borrowed from the engine in callbacks are only given as `&SessionId`, which
+// is not `Send` since `SessionId` is not `Sync`.
+unsafe impl Send for SessionId {}
+
 impl Drop for SessionId {
     fn drop(&mut self) {
         unsafe { FixSessionID_delete(self.0) }
@@ -0,0 +1,87 @@
+use crate::QuickFixError;
+
+/// Reason of a session level reject (`SessionRejectReason` field, tag 373).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[repr(i32)]
+#[allow(missing_docs)]
+pub enum SessionRejectReason {
+    InvalidTagNumber = 0,
+    RequiredTagMissing = 1,
+    TagNotDefinedForThisMessageType = 2,
+    UndefinedTag = 3,
+    TagSpecifiedWithoutAValue = 4,
+    ValueIsIncorrect = 5,
+    IncorrectDataFormatForValue = 6,
+    DecryptionProblem = 7,
+    SignatureProblem = 8,
+    CompIdProblem = 9,
+    SendingTimeAccuracyProblem = 10,
+    InvalidMsgType = 11,
+    XmlValidationError = 12,
+    TagAppearsMoreThanOnce = 13,
+    TagSpecifiedOutOfRequiredOrder = 14,
+    RepeatingGroupFieldsOutOfOrder = 15,
+    IncorrectNumInGroupCountForRepeatingGroup = 16,
+    NonDataValueIncludesFieldDelimiter = 17,
+    InvalidUnsupportedApplicationVersion = 18,
+    Other = 99,
+}
+
+impl SessionRejectReason {
+    /// Get reject reason matching a validation error, if any.
+    ///
+    /// Mapping follows what quickfix session does when receiving an invalid message.
+    pub fn from_error(error: &QuickFixError) -> Option<Self> {
+        match error {
+            QuickFixError::InvalidTagNumber(_) => Some(Self::InvalidTagNumber),
+            QuickFixError::RequiredTagMissing(_) | QuickFixError::FieldNotFound(_) => {
+                Some(Self::RequiredTagMissing)
+            }
+            QuickFixError::TagNotDefinedForMessage(_) => {
+                Some(Self::TagNotDefinedForThisMessageType)
+            }
+            QuickFixError::NoTagValue(_) => Some(Self::TagSpecifiedWithoutAValue),
+            QuickFixError::IncorrectTagValue(_) => Some(Self::ValueIsIncorrect),
+            QuickFixError::IncorrectDataFormat(_) => Some(Self::IncorrectDataFormatForValue),
+            QuickFixError::InvalidMessageType(_) => Some(Self::InvalidMsgType),
+            QuickFixError::RepeatedTag(_) => Some(Self::TagAppearsMoreThanOnce),
+            QuickFixError::TagOutOfOrder(_) => Some(Self::TagSpecifiedOutOfRequiredOrder),
+            QuickFixError::RepeatingGroupCountMismatch(_) => {
+                Some(Self::IncorrectNumInGroupCountForRepeatingGroup)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<i32> for SessionRejectReason {
+    type Error = QuickFixError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::InvalidTagNumber),
+            1 => Ok(Self::RequiredTagMissing),
+            2 => Ok(Self::TagNotDefinedForThisMessageType),
+            3 => Ok(Self::UndefinedTag),
+            4 => Ok(Self::TagSpecifiedWithoutAValue),
+            5 => Ok(Self::ValueIsIncorrect),
+            6 => Ok(Self::IncorrectDataFormatForValue),
+            7 => Ok(Self::DecryptionProblem),
+            8 => Ok(Self::SignatureProblem),
+            9 => Ok(Self::CompIdProblem),
+            10 => Ok(Self::SendingTimeAccuracyProblem),
+            11 => Ok(Self::InvalidMsgType),
+            12 => Ok(Self::XmlValidationError),
+            13 => Ok(Self::TagAppearsMoreThanOnce),
+            14 => Ok(Self::TagSpecifiedOutOfRequiredOrder),
+            15 => Ok(Self::RepeatingGroupFieldsOutOfOrder),
+            16 => Ok(Self::IncorrectNumInGroupCountForRepeatingGroup),
+            17 => Ok(Self::NonDataValueIncludesFieldDelimiter),
+            18 => Ok(Self::InvalidUnsupportedApplicationVersion),
+            99 => Ok(Self::Other),
+            _ => Err(QuickFixError::InvalidArgument(format!(
+                "Invalid session reject reason: {value}"
+            ))),
+        }
+    }
+}
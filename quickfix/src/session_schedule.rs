@@ -0,0 +1,308 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    dictionary_item::DictionaryItem, DayOfWeek, Dictionary, FromFixValue, QuickFixError,
+    UtcTimeOnly,
+};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+
+/// Time window of a session, evaluated the same way as quickfix C++ `TimeRange`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimeRange {
+    start_time: UtcTimeOnly,
+    end_time: UtcTimeOnly,
+    /// Start and end days of weekly ranges.
+    days: Option<(DayOfWeek, DayOfWeek)>,
+}
+
+impl TimeRange {
+    fn contains(&self, instant: i128) -> bool {
+        let day = (instant.div_euclid(NANOS_PER_DAY) + 4).rem_euclid(7) as i32 + 1;
+        self.contains_at(Some(day), instant.rem_euclid(NANOS_PER_DAY))
+    }
+
+    fn contains_at(&self, day: Option<i32>, time: i128) -> bool {
+        let start_time = nanos_of_day(&self.start_time);
+        let end_time = nanos_of_day(&self.end_time);
+        let in_daily_range = || {
+            if start_time < end_time {
+                time >= start_time && time <= end_time
+            } else {
+                time >= start_time || time <= end_time
+            }
+        };
+
+        let (Some((start_day, end_day)), Some(day)) = (self.days, day) else {
+            return in_daily_range();
+        };
+        let (start_day, end_day) = (start_day as i32, end_day as i32);
+
+        if start_day == end_day {
+            day != start_day || in_daily_range()
+        } else if (start_day < end_day && (day < start_day || day > end_day))
+            || (start_day > end_day && day < start_day && day > end_day)
+        {
+            false
+        } else {
+            !((day == start_day && time < start_time) || (day == end_day && time > end_time))
+        }
+    }
+
+    /// First instant at or after `from` where range opens.
+    fn next_start(&self, from: i128) -> Option<i128> {
+        let start_time = nanos_of_day(&self.start_time);
+        Self::candidates(from, start_time)
+            .find(|&instant| self.contains(instant) && !self.contains(instant - 1))
+    }
+
+    /// Last instant, at or after `from`, of the range currently open or next to open.
+    fn next_end(&self, from: i128) -> Option<i128> {
+        let end_time = nanos_of_day(&self.end_time);
+        Self::candidates(from, end_time)
+            .find(|&instant| self.contains(instant) && !self.contains(instant + 1))
+    }
+
+    /// A week of instants at a given time of day, starting at `from`.
+    fn candidates(from: i128, time: i128) -> impl Iterator<Item = i128> {
+        let first_day = from.div_euclid(NANOS_PER_DAY);
+        (first_day..=first_day + 7)
+            .map(move |day| day * NANOS_PER_DAY + time)
+            .filter(move |&instant| instant >= from)
+    }
+}
+
+/// Session and logon time windows, as configured by `StartTime`, `EndTime`, `StartDay`,
+/// `EndDay`, `LogonTime`, `LogoutTime`, `LogonDay`, `LogoutDay`, `NonStopSession` and
+/// `UseLocalTime`.
+///
+/// Schedule is evaluated in Rust, with the same rules as quickfix C++ session:
+/// - Daily sessions run from start time to end time, and wrap over midnight when start time
+///   is after end time.
+/// - Weekly sessions run from start day / time to end day / time, and wrap over the weekend
+///   when start day is after end day.
+/// - Both boundaries are part of the session.
+///
+/// Rust standard library has no access to the system time zone: schedules using local time
+/// must be read with [`SessionSchedule::try_from_dictionary_with_local_time`].
+#[derive(Debug, Clone)]
+pub struct SessionSchedule {
+    /// Session time window, `None` when session never stops.
+    session: Option<TimeRange>,
+    /// Time window where initiator is allowed to logon.
+    logon: Option<TimeRange>,
+    /// Local time offset, `None` when schedule uses UTC.
+    local_time_offset: Option<LocalTimeOffset>,
+}
+
+/// Offset from UTC, in seconds, of local time at a given instant.
+///
+/// Quickfix C++ uses the process time zone, so the function should follow the same daylight
+/// saving time changes.
+pub type LocalTimeOffset = fn(SystemTime) -> i32;
+
+impl SessionSchedule {
+    /// Try to read schedule from session settings.
+    ///
+    /// Settings using `UseLocalTime` are rejected, as local time offset is unknown.
+    pub fn try_from_dictionary(settings: &Dictionary) -> Result<Self, QuickFixError> {
+        if read_bool(settings, "UseLocalTime")? {
+            return Err(config_error(
+                "UseLocalTime requires a local time offset function",
+            ));
+        }
+        Self::read(settings, None)
+    }
+
+    /// Try to read schedule from session settings, evaluating local times with a given offset.
+    ///
+    /// Offset function is ignored when `UseLocalTime` is not enabled.
+    pub fn try_from_dictionary_with_local_time(
+        settings: &Dictionary,
+        local_time_offset: LocalTimeOffset,
+    ) -> Result<Self, QuickFixError> {
+        let use_local_time = read_bool(settings, "UseLocalTime")?;
+        Self::read(settings, use_local_time.then_some(local_time_offset))
+    }
+
+    fn read(
+        settings: &Dictionary,
+        local_time_offset: Option<LocalTimeOffset>,
+    ) -> Result<Self, QuickFixError> {
+        let is_non_stop = read_bool(settings, "NonStopSession")?;
+
+        let start_day = read_day(settings, "StartDay")?;
+        let end_day = read_day(settings, "EndDay")?;
+        let days = match (start_day, end_day) {
+            (Some(start_day), Some(end_day)) => Some((start_day, end_day)),
+            (Some(_), None) => return Err(config_error("StartDay used without EndDay")),
+            (None, Some(_)) => return Err(config_error("EndDay used without StartDay")),
+            (None, None) => None,
+        };
+
+        let start_time = read_time(settings, "StartTime")?;
+        let end_time = read_time(settings, "EndTime")?;
+
+        if is_non_stop {
+            let midnight =
+                |time: Option<UtcTimeOnly>| time.map_or(true, |time| nanos_of_day(&time) == 0);
+            if days.is_some() || !midnight(start_time) || !midnight(end_time) {
+                return Err(config_error(
+                    "NonStopSession used with StartTime/EndTime/StartDay/EndDay",
+                ));
+            }
+            return Ok(Self {
+                session: None,
+                logon: None,
+                local_time_offset,
+            });
+        }
+
+        let (Some(start_time), Some(end_time)) = (start_time, end_time) else {
+            return Err(config_error("StartTime and EndTime are required"));
+        };
+        let session = TimeRange {
+            start_time,
+            end_time,
+            days,
+        };
+
+        let logon_time = read_time(settings, "LogonTime")?.unwrap_or(start_time);
+        let logout_time = read_time(settings, "LogoutTime")?.unwrap_or(end_time);
+        let logon_day = read_day(settings, "LogonDay")?.or(start_day);
+        let logout_day = read_day(settings, "LogoutDay")?.or(end_day);
+
+        if !session.contains_at(logon_day.map(|day| day as i32), nanos_of_day(&logon_time)) {
+            return Err(config_error(
+                "LogonTime must be between StartTime and EndTime",
+            ));
+        }
+        if !session.contains_at(logout_day.map(|day| day as i32), nanos_of_day(&logout_time)) {
+            return Err(config_error(
+                "LogoutTime must be between StartTime and EndTime",
+            ));
+        }
+
+        Ok(Self {
+            session: Some(session),
+            logon: Some(TimeRange {
+                start_time: logon_time,
+                end_time: logout_time,
+                days: logon_day.zip(logout_day),
+            }),
+            local_time_offset,
+        })
+    }
+
+    /// Try to build schedule from multiple setting items.
+    pub fn try_from_items(items: &[&dyn DictionaryItem]) -> Result<Self, QuickFixError> {
+        Self::try_from_dictionary(&Dictionary::try_from_items(items)?)
+    }
+
+    /// Check if schedule uses local time instead of UTC.
+    pub fn use_local_time(&self) -> bool {
+        self.local_time_offset.is_some()
+    }
+
+    /// Check if session never stops.
+    pub fn is_non_stop(&self) -> bool {
+        self.session.is_none()
+    }
+
+    /// Check if session is active at a given instant.
+    pub fn is_in_session(&self, instant: SystemTime) -> bool {
+        self.session
+            .map_or(true, |range| range.contains(self.schedule_time(instant)))
+    }
+
+    /// Check if initiator is allowed to logon at a given instant.
+    pub fn is_logon_time(&self, instant: SystemTime) -> bool {
+        self.logon
+            .map_or(true, |range| range.contains(self.schedule_time(instant)))
+    }
+
+    /// Get when session starts, at or after a given instant.
+    ///
+    /// Returns `None` if session never stops.
+    pub fn next_start(&self, instant: SystemTime) -> Option<SystemTime> {
+        let range = self.session?;
+        let start = range.next_start(self.schedule_time(instant))?;
+        Some(self.system_time(start))
+    }
+
+    /// Get when session ends, at or after a given instant.
+    ///
+    /// End instant is the last one where session is still active.
+    /// Returns `None` if session never stops.
+    pub fn next_end(&self, instant: SystemTime) -> Option<SystemTime> {
+        let range = self.session?;
+        let end = range.next_end(self.schedule_time(instant))?;
+        Some(self.system_time(end))
+    }
+
+    fn schedule_time(&self, instant: SystemTime) -> i128 {
+        let offset = self.local_time_offset.map_or(0, |offset| offset(instant));
+        nanos_since_epoch(instant) + i128::from(offset) * NANOS_PER_SECOND
+    }
+
+    fn system_time(&self, instant: i128) -> SystemTime {
+        let Some(offset) = self.local_time_offset else {
+            return from_nanos_since_epoch(instant);
+        };
+        // Offset may change between the local instant read as UTC and the real one, so use the
+        // offset in effect at a first estimate of the result.
+        let offset_at = |estimate: i128| i128::from(offset(from_nanos_since_epoch(estimate)));
+        let estimate = instant - offset_at(instant) * NANOS_PER_SECOND;
+        from_nanos_since_epoch(instant - offset_at(estimate) * NANOS_PER_SECOND)
+    }
+}
+
+fn nanos_since_epoch(instant: SystemTime) -> i128 {
+    match instant.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_nanos() as i128,
+        Err(err) => -(err.duration().as_nanos() as i128),
+    }
+}
+
+fn from_nanos_since_epoch(nanos: i128) -> SystemTime {
+    let duration = Duration::new(
+        (nanos.unsigned_abs() / NANOS_PER_SECOND as u128) as u64,
+        (nanos.unsigned_abs() % NANOS_PER_SECOND as u128) as u32,
+    );
+    if nanos >= 0 {
+        UNIX_EPOCH + duration
+    } else {
+        UNIX_EPOCH - duration
+    }
+}
+
+fn nanos_of_day(time: &UtcTimeOnly) -> i128 {
+    let seconds = i128::from(time.hour) * 3600 + i128::from(time.minute) * 60;
+    (seconds + i128::from(time.second)) * NANOS_PER_SECOND + i128::from(time.nanosecond)
+}
+
+fn config_error(msg: &str) -> QuickFixError {
+    QuickFixError::ConfigError(msg.to_string())
+}
+
+fn read_bool(settings: &Dictionary, key: &str) -> Result<bool, QuickFixError> {
+    Ok(settings.contains(key)? && settings.get::<bool>(key)?)
+}
+
+fn read_day(settings: &Dictionary, key: &str) -> Result<Option<DayOfWeek>, QuickFixError> {
+    if !settings.contains(key)? {
+        return Ok(None);
+    }
+    settings.get(key).map(Some)
+}
+
+fn read_time(settings: &Dictionary, key: &str) -> Result<Option<UtcTimeOnly>, QuickFixError> {
+    if !settings.contains(key)? {
+        return Ok(None);
+    }
+    let value: String = settings.get(key)?;
+    UtcTimeOnly::from_fix_value(&value)
+        .map(Some)
+        .ok_or_else(|| QuickFixError::ConfigError(format!("Invalid {key}: {value}")))
+}
@@ -2,11 +2,14 @@ use std::{ffi::CString, fmt, mem::ManuallyDrop, path::Path};
 
 use quickfix_ffi::{
     FixSessionSettings_delete, FixSessionSettings_fromPath, FixSessionSettings_getGlobalRef,
-    FixSessionSettings_getSessionRef, FixSessionSettings_new, FixSessionSettings_setGlobal,
-    FixSessionSettings_setSession, FixSessionSettings_t,
+    FixSessionSettings_getSessionRef, FixSessionSettings_new, FixSessionSettings_readSessions,
+    FixSessionSettings_setGlobal, FixSessionSettings_setSession, FixSessionSettings_t,
 };
 
-use crate::{utils::ffi_code_to_result, Dictionary, QuickFixError, SessionId};
+use crate::{
+    utils::{ffi_code_to_result, push_session_id_to_vec},
+    Dictionary, QuickFixError, SessionId,
+};
 
 /// Container for setting dictionaries mapped to sessions.
 pub struct SessionSettings(pub(crate) FixSessionSettings_t);
@@ -52,6 +55,19 @@ impl SessionSettings {
         }
     }
 
+    /// List IDs of all configured sessions.
+    pub fn session_ids(&self) -> Result<Vec<SessionId>, QuickFixError> {
+        let mut session_ids = Vec::new();
+        ffi_code_to_result(unsafe {
+            FixSessionSettings_readSessions(
+                self.0,
+                (&mut session_ids as *mut Vec<SessionId>).cast(),
+                push_session_id_to_vec,
+            )
+        })?;
+        Ok(session_ids)
+    }
+
     /// Set dictionary parameter for session or global configuration.
     pub fn set(
         &mut self,
@@ -0,0 +1,420 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{session::send_to_target_mut, Message, QuickFixError, SessionId};
+
+const MSG_TYPE_ORDER_CANCEL_REQUEST: &str = "F";
+const MSG_TYPE_ORDER_MASS_CANCEL_REQUEST: &str = "q";
+
+/// What to do with a message when session rate limit is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThrottlePolicy {
+    /// Queue message, it will be sent by a next call to [`OutboundThrottle::send`] or
+    /// [`OutboundThrottle::flush`].
+    #[default]
+    Queue,
+    /// Give message back to the caller.
+    Reject,
+    /// Wait in the calling thread until message can be sent.
+    Block,
+}
+
+/// Order in which queued messages are sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SendPriority {
+    /// Sent before any normal priority message.
+    High,
+    /// Sent in order of arrival.
+    Normal,
+}
+
+impl SendPriority {
+    /// Get default priority of a message: cancel requests are sent before other messages.
+    pub fn of(msg: &Message) -> Self {
+        match msg.msg_type().as_deref() {
+            Some(MSG_TYPE_ORDER_CANCEL_REQUEST | MSG_TYPE_ORDER_MASS_CANCEL_REQUEST) => Self::High,
+            _ => Self::Normal,
+        }
+    }
+}
+
+/// Outcome of [`OutboundThrottle::send`].
+#[derive(Debug)]
+pub enum ThrottledSend {
+    /// Message has been given to the session.
+    Sent,
+    /// Message is waiting for the rate limit to allow it.
+    Queued,
+    /// Rate limit is reached and message has not been sent.
+    Rejected(Message),
+}
+
+/// Outcome of [`OutboundThrottle::flush`].
+#[derive(Debug, Default)]
+pub struct ThrottleFlush {
+    /// Number of messages given to the sessions.
+    pub sent: usize,
+    /// Sessions that failed to send a queued message, which is kept for next flush.
+    pub errors: Vec<(SessionId, QuickFixError)>,
+}
+
+/// Throttle counters of a session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ThrottleMetrics {
+    /// Number of messages currently queued.
+    pub queue_depth: usize,
+    /// Highest number of messages queued at the same time.
+    pub max_queue_depth: usize,
+    /// Number of messages given to the session.
+    pub sent: u64,
+    /// Number of messages that had to be queued.
+    pub queued: u64,
+    /// Number of messages rejected.
+    pub rejected: u64,
+}
+
+#[derive(Debug)]
+struct SessionQueue {
+    session_id: SessionId,
+    max_messages: usize,
+    window: Duration,
+    sent_at: VecDeque<Instant>,
+    high: VecDeque<Message>,
+    normal: VecDeque<Message>,
+    metrics: ThrottleMetrics,
+}
+
+impl SessionQueue {
+    fn new(session_id: &SessionId, max_messages: usize, window: Duration) -> Self {
+        Self {
+            session_id: session_id.clone(),
+            max_messages,
+            window,
+            sent_at: VecDeque::new(),
+            high: VecDeque::new(),
+            normal: VecDeque::new(),
+            metrics: ThrottleMetrics::default(),
+        }
+    }
+
+    fn has_queued(&self, priority: SendPriority) -> bool {
+        match priority {
+            SendPriority::High => !self.high.is_empty(),
+            SendPriority::Normal => !self.high.is_empty() || !self.normal.is_empty(),
+        }
+    }
+
+    fn push(&mut self, msg: Message, priority: SendPriority) {
+        match priority {
+            SendPriority::High => self.high.push_back(msg),
+            SendPriority::Normal => self.normal.push_back(msg),
+        }
+        self.metrics.queued += 1;
+        self.metrics.queue_depth += 1;
+        self.metrics.max_queue_depth = self.metrics.max_queue_depth.max(self.metrics.queue_depth);
+    }
+
+    /// Put back a message that failed to be sent, so it is the next one sent.
+    fn push_front(&mut self, msg: Message, priority: SendPriority) {
+        match priority {
+            SendPriority::High => self.high.push_front(msg),
+            SendPriority::Normal => self.normal.push_front(msg),
+        }
+        self.metrics.queue_depth += 1;
+        self.metrics.max_queue_depth = self.metrics.max_queue_depth.max(self.metrics.queue_depth);
+    }
+
+    fn pop(&mut self) -> Option<(Message, SendPriority)> {
+        let (msg, priority) = match self.high.pop_front() {
+            Some(msg) => (msg, SendPriority::High),
+            None => (self.normal.pop_front()?, SendPriority::Normal),
+        };
+        self.metrics.queue_depth -= 1;
+        Some((msg, priority))
+    }
+
+    /// Get how long to wait before a message can be sent, and reserve a slot if none.
+    fn acquire(&mut self, now: Instant) -> Option<Duration> {
+        while self
+            .sent_at
+            .front()
+            .is_some_and(|sent_at| now.duration_since(*sent_at) >= self.window)
+        {
+            self.sent_at.pop_front();
+        }
+
+        if self.sent_at.len() < self.max_messages {
+            self.sent_at.push_back(now);
+            None
+        } else {
+            let oldest = self.sent_at.front().copied().unwrap_or(now);
+            Some(self.window.saturating_sub(now.duration_since(oldest)))
+        }
+    }
+
+    /// Count a message sent with the slot reserved by `acquire`, or give the slot back.
+    fn complete(&mut self, is_sent: bool) {
+        if is_sent {
+            self.metrics.sent += 1;
+        } else {
+            self.sent_at.pop_back();
+        }
+    }
+
+    /// Get how long to wait before next queued message can be sent.
+    fn next_send_in(&self, now: Instant) -> Duration {
+        match self.sent_at.front() {
+            Some(oldest) if self.sent_at.len() >= self.max_messages => {
+                self.window.saturating_sub(now.duration_since(*oldest))
+            }
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// Limit how many messages per time window are sent to each session.
+///
+/// Throttle sits in front of [`send_to_target`]: every message sent with
+/// [`OutboundThrottle::send`] is counted against the limit of its session. When the limit is
+/// reached, message is queued, rejected or the caller is blocked, depending on
+/// [`ThrottlePolicy`].
+///
+/// Throttle does not run any thread: queued messages are sent on next calls to
+/// [`OutboundThrottle::send`] or [`OutboundThrottle::flush`]. High priority messages are
+/// sent before normal ones.
+///
+/// Only messages accepted by the session count against the limit. A message the session fails
+/// to send is put back in front of its queue and the error is returned, so it is sent again
+/// on next flush.
+#[derive(Debug)]
+pub struct OutboundThrottle {
+    max_messages: usize,
+    window: Duration,
+    policy: ThrottlePolicy,
+    sessions: Mutex<HashMap<String, SessionQueue>>,
+}
+
+impl OutboundThrottle {
+    /// Try to create a throttle allowing `max_messages` per `window` to each session.
+    ///
+    /// Use [`OutboundThrottle::set_limit`] to give a session its own limit.
+    pub fn try_new(
+        max_messages: usize,
+        window: Duration,
+        policy: ThrottlePolicy,
+    ) -> Result<Self, QuickFixError> {
+        check_limit(max_messages, window)?;
+
+        Ok(Self {
+            max_messages,
+            window,
+            policy,
+            sessions: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Allow `max_messages` per `window` to a session, instead of the throttle default limit.
+    ///
+    /// Messages already sent in the current window count against the new limit.
+    pub fn set_limit(
+        &self,
+        session_id: &SessionId,
+        max_messages: usize,
+        window: Duration,
+    ) -> Result<(), QuickFixError> {
+        check_limit(max_messages, window)?;
+
+        let mut sessions = self.lock();
+        let queue = self.queue(&mut sessions, session_id);
+        queue.max_messages = max_messages;
+        queue.window = window;
+        Ok(())
+    }
+
+    /// Get what is done with messages when rate limit is reached.
+    pub fn policy(&self) -> ThrottlePolicy {
+        self.policy
+    }
+
+    /// Send message to target design in session ID, with its default [`SendPriority`].
+    pub fn send(
+        &self,
+        msg: Message,
+        session_id: &SessionId,
+    ) -> Result<ThrottledSend, QuickFixError> {
+        let priority = SendPriority::of(&msg);
+        self.send_with_priority(msg, session_id, priority)
+    }
+
+    /// Send message to target design in session ID.
+    ///
+    /// Messages already queued for the session are sent first, so a message is never sent
+    /// before a queued one of the same or higher priority.
+    ///
+    /// If the session fails to send the message or a queued one, message is queued and the
+    /// error is returned.
+    pub fn send_with_priority(
+        &self,
+        msg: Message,
+        session_id: &SessionId,
+        priority: SendPriority,
+    ) -> Result<ThrottledSend, QuickFixError> {
+        loop {
+            if let Err(err) = self.flush_session(session_id) {
+                // Message cannot be sent before queued ones, so it waits for next flush.
+                let mut sessions = self.lock();
+                self.queue(&mut sessions, session_id).push(msg, priority);
+                return Err(err);
+            }
+
+            let wait = {
+                let mut sessions = self.lock();
+                let queue = self.queue(&mut sessions, session_id);
+
+                // Messages still queued after flush are waiting for the rate limit too.
+                let wait = if queue.has_queued(priority) {
+                    Some(queue.next_send_in(Instant::now()))
+                } else {
+                    queue.acquire(Instant::now())
+                };
+
+                match wait {
+                    None => break,
+                    Some(wait) => match self.policy {
+                        ThrottlePolicy::Queue => {
+                            queue.push(msg, priority);
+                            return Ok(ThrottledSend::Queued);
+                        }
+                        ThrottlePolicy::Reject => {
+                            queue.metrics.rejected += 1;
+                            return Ok(ThrottledSend::Rejected(msg));
+                        }
+                        ThrottlePolicy::Block => wait,
+                    },
+                }
+            };
+            thread::sleep(wait);
+        }
+
+        // Lock is released here: sending may call application callbacks using this throttle.
+        self.send_reserved(msg, priority, session_id)?;
+        Ok(ThrottledSend::Sent)
+    }
+
+    /// Send queued messages allowed by the rate limit of every session.
+    ///
+    /// A session failing to send does not prevent the others from being flushed.
+    pub fn flush(&self) -> ThrottleFlush {
+        let session_ids: Vec<_> = self
+            .lock()
+            .values()
+            .map(|queue| queue.session_id.clone())
+            .collect();
+
+        let mut output = ThrottleFlush::default();
+        for session_id in session_ids {
+            match self.flush_session(&session_id) {
+                Ok(count) => output.sent += count,
+                Err(err) => output.errors.push((session_id, err)),
+            }
+        }
+        output
+    }
+
+    /// Get how long to wait before next queued message can be sent.
+    ///
+    /// Returns `None` when nothing is queued.
+    pub fn next_flush_in(&self) -> Option<Duration> {
+        let now = Instant::now();
+        self.lock()
+            .values()
+            .filter(|queue| queue.metrics.queue_depth > 0)
+            .map(|queue| queue.next_send_in(now))
+            .min()
+    }
+
+    /// Get throttle counters of a session.
+    pub fn metrics(&self, session_id: &SessionId) -> ThrottleMetrics {
+        self.lock()
+            .get(&session_id.to_repr())
+            .map(|queue| queue.metrics)
+            .unwrap_or_default()
+    }
+
+    /// Get number of messages queued for a session.
+    pub fn queue_depth(&self, session_id: &SessionId) -> usize {
+        self.metrics(session_id).queue_depth
+    }
+
+    fn flush_session(&self, session_id: &SessionId) -> Result<usize, QuickFixError> {
+        let mut count = 0;
+        loop {
+            let (msg, priority) = {
+                let mut sessions = self.lock();
+                let Some(queue) = sessions.get_mut(&session_id.to_repr()) else {
+                    return Ok(count);
+                };
+                if !queue.has_queued(SendPriority::Normal)
+                    || queue.acquire(Instant::now()).is_some()
+                {
+                    return Ok(count);
+                }
+                queue.pop().expect("Queue cannot be empty")
+            };
+
+            self.send_reserved(msg, priority, session_id)?;
+            count += 1;
+        }
+    }
+
+    /// Send a message using the slot reserved for it, or put it back in front of its queue.
+    fn send_reserved(
+        &self,
+        mut msg: Message,
+        priority: SendPriority,
+        session_id: &SessionId,
+    ) -> Result<(), QuickFixError> {
+        let result = send_to_target_mut(&mut msg, session_id);
+
+        let mut sessions = self.lock();
+        let queue = self.queue(&mut sessions, session_id);
+        queue.complete(result.is_ok());
+        if result.is_err() {
+            queue.push_front(msg, priority);
+        }
+        result
+    }
+
+    fn queue<'a>(
+        &self,
+        sessions: &'a mut HashMap<String, SessionQueue>,
+        session_id: &SessionId,
+    ) -> &'a mut SessionQueue {
+        sessions
+            .entry(session_id.to_repr())
+            .or_insert_with(|| SessionQueue::new(session_id, self.max_messages, self.window))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, SessionQueue>> {
+        // Queues stay consistent even if a thread panicked while holding the lock.
+        self.sessions.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+fn check_limit(max_messages: usize, window: Duration) -> Result<(), QuickFixError> {
+    if max_messages == 0 {
+        return Err(QuickFixError::invalid_argument(
+            "Throttle must allow at least one message",
+        ));
+    }
+    if window.is_zero() {
+        return Err(QuickFixError::invalid_argument(
+            "Throttle window cannot be empty",
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use std::{
+    future::Future,
+    panic,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_core::Stream;
+use tokio::{
+    runtime::{Handle, RuntimeFlavor},
+    sync::{mpsc, Semaphore},
+    task,
+    time::{self, Instant},
+};
+
+use crate::{
+    send_to_target, ApplicationCallback, ConnectionHandler, FfiMessageStoreFactory, FixEngine,
+    FixEvent, LogCallback, Message, QuickFixError, SessionContainer, SessionId,
+};
+
+const LOGON_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Run blocking quickfix code on tokio blocking thread pool.
+async fn run_blocking<T, F>(f: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    match task::spawn_blocking(f).await {
+        Ok(value) => value,
+        Err(err) => panic::resume_unwind(err.into_panic()),
+    }
+}
+
+/// Run blocking quickfix code borrowing caller data, from an async context.
+///
+/// Borrowed data cannot be moved to tokio blocking thread pool: multi threaded runtime workers
+/// hand over their tasks before running it, current thread runtime runs it in place.
+fn run_blocking_in_place<T>(f: impl FnOnce() -> T) -> T {
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            task::block_in_place(f)
+        }
+        _ => f(),
+    }
+}
+
+/// Send an event from sync code, waiting for room in the channel when the thread can block.
+///
+/// Quickfix may call back application from a runtime thread (when starting engine or sending
+/// messages). Multi threaded runtime workers hand over their tasks before waiting, but current
+/// thread runtime cannot wait at all: event is then only sent if there is room.
+pub(crate) fn blocking_send(sender: &mpsc::Sender<FixEvent>, event: FixEvent) -> bool {
+    match Handle::try_current() {
+        Err(_) => sender.blocking_send(event).is_ok(),
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            task::block_in_place(|| sender.blocking_send(event)).is_ok()
+        }
+        Ok(_) => sender.try_send(event).is_ok(),
+    }
+}
+
+/// Async stream of events sent by a [`crate::ChannelApplication`].
+///
+/// Use [`crate::ChannelApplication::new_async`] to build it. Stream ends once application is
+/// dropped.
+#[derive(Debug)]
+pub struct FixEventStream(mpsc::Receiver<FixEvent>);
+
+impl FixEventStream {
+    pub(crate) fn new(receiver: mpsc::Receiver<FixEvent>) -> Self {
+        Self(receiver)
+    }
+
+    /// Receive next event.
+    pub async fn recv(&mut self) -> Option<FixEvent> {
+        self.0.recv().await
+    }
+
+    /// Stop receiving events.
+    ///
+    /// Events already in the stream can still be received. Next ones are dropped.
+    pub fn close(&mut self) {
+        self.0.close();
+    }
+}
+
+impl Stream for FixEventStream {
+    type Item = FixEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_recv(cx)
+    }
+}
+
+/// Send messages from async code.
+///
+/// Messages are sent on tokio blocking thread pool, since sending waits for the session lock
+/// and may run application callbacks. Callers wait when too many messages are being sent.
+#[derive(Debug, Clone)]
+pub struct AsyncSender {
+    permits: Arc<Semaphore>,
+}
+
+impl AsyncSender {
+    /// Try create new struct allowing at most `max_in_flight` messages to be sent concurrently.
+    pub fn try_new(max_in_flight: usize) -> Result<Self, QuickFixError> {
+        if max_in_flight == 0 || max_in_flight > Semaphore::MAX_PERMITS {
+            return Err(QuickFixError::invalid_argument(format!(
+                "Invalid max in flight message count: {max_in_flight}"
+            )));
+        }
+
+        Ok(Self {
+            permits: Arc::new(Semaphore::new(max_in_flight)),
+        })
+    }
+
+    /// Send message to target, waiting for a free slot first.
+    ///
+    /// See [`send_to_target`].
+    pub fn send(
+        &self,
+        msg: Message,
+        session_id: &SessionId,
+    ) -> impl Future<Output = Result<(), QuickFixError>> + Send + 'static {
+        // Session ID is not `Sync`: it must not be borrowed by the returned future.
+        let session_id = session_id.clone();
+        let permits = Arc::clone(&self.permits);
+
+        async move {
+            let _permit = permits.acquire().await.expect("Semaphore is never closed");
+            run_blocking(move || send_to_target(msg, &session_id)).await
+        }
+    }
+
+    /// Get how many messages can be sent right now without waiting.
+    pub fn available_slots(&self) -> usize {
+        self.permits.available_permits()
+    }
+}
+
+impl<A, L, S> FixEngine<A, L, S>
+where
+    A: ApplicationCallback + 'static,
+    S: FfiMessageStoreFactory + 'static,
+    L: LogCallback + 'static,
+{
+    /// Wait for a session to be logged on.
+    ///
+    /// Session not created yet is considered as not logged on.
+    /// Return `false` if session is still not logged on after `timeout`.
+    pub async fn wait_logged_on(
+        &self,
+        session_id: &SessionId,
+        timeout: Duration,
+    ) -> Result<bool, QuickFixError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let is_logged_on = run_blocking_in_place(|| match self.session(session_id.clone()) {
+                Ok(mut session) => session.is_logged_on(),
+                Err(QuickFixError::SessionNotFound(_)) => Ok(false),
+                Err(err) => Err(err),
+            })?;
+            if is_logged_on {
+                return Ok(true);
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(false);
+            }
+            time::sleep(LOGON_POLL_INTERVAL.min(deadline - now)).await;
+        }
+    }
+}
+
+impl<A, L, S> FixEngine<A, L, S>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+    S: FfiMessageStoreFactory + Send + 'static,
+    L: LogCallback + Send + Sync + 'static,
+{
+    /// Stop engine and release all its components, without blocking async runtime.
+    ///
+    /// Stopping waits for sessions to logout, which may take several seconds.
+    pub async fn shutdown(self) -> Result<(), QuickFixError> {
+        run_blocking(move || {
+            let mut engine = self;
+            engine.stop()
+        })
+        .await
+    }
+}
@@ -2,12 +2,15 @@ use std::fmt;
 
 use quickfix_ffi::{
     FixTrailer_addGroup, FixTrailer_copy, FixTrailer_copyGroup, FixTrailer_delete,
-    FixTrailer_getField, FixTrailer_new, FixTrailer_removeField, FixTrailer_setField, FixTrailer_t,
+    FixTrailer_getField, FixTrailer_getFieldBytes, FixTrailer_getFieldIterator,
+    FixTrailer_getGroupCount, FixTrailer_new, FixTrailer_removeAllGroups, FixTrailer_removeField,
+    FixTrailer_removeGroup, FixTrailer_replaceGroup, FixTrailer_setField, FixTrailer_setFieldBytes,
+    FixTrailer_t, FixTrailer_writeBytes,
 };
 
 use crate::{
-    utils::{ffi_code_to_result, read_checked_cstr},
-    FieldMap, Group, IntoFixValue, QuickFixError,
+    utils::{append_to_vec, ffi_code_to_result, read_checked_bytes, read_checked_cstr},
+    FieldIterator, FieldMap, Group, IntoFixValue, QuickFixError,
 };
 
 /// Trailer part of a FIX message.
@@ -18,6 +21,15 @@ impl Trailer {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Append trailer fields bytes to given buffer.
+    ///
+    /// Buffer is not cleared, so it can be reused from one call to another to avoid new allocations.
+    pub fn write_fix_bytes(&self, buffer: &mut Vec<u8>) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixTrailer_writeBytes(self.0, (buffer as *mut Vec<u8>).cast(), append_to_vec)
+        })
+    }
 }
 
 impl FieldMap for Trailer {
@@ -30,6 +42,18 @@ impl FieldMap for Trailer {
         ffi_code_to_result(unsafe { FixTrailer_setField(self.0, tag, fix_value.as_ptr()) })
     }
 
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        let mut len = 0;
+        unsafe { FixTrailer_getFieldBytes(self.0, tag, &mut len) }
+            .map(|ptr| read_checked_bytes(ptr, len))
+    }
+
+    fn set_field_bytes(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixTrailer_setFieldBytes(self.0, tag, value.as_ptr().cast(), value.len() as u64)
+        })
+    }
+
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError> {
         ffi_code_to_result(unsafe { FixTrailer_removeField(self.0, tag) })
     }
@@ -42,6 +66,29 @@ impl FieldMap for Trailer {
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group> {
         unsafe { FixTrailer_copyGroup(self.0, index, tag) }.map(Group)
     }
+
+    fn remove_group(&mut self, index: i32, tag: i32) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixTrailer_removeGroup(self.0, index, tag) })
+    }
+
+    fn replace_group(&mut self, index: i32, group: &Group) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixTrailer_replaceGroup(self.0, index, group.0) })
+    }
+
+    fn remove_all_groups(&mut self, tag: i32) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixTrailer_removeAllGroups(self.0, tag) })
+    }
+
+    fn group_count(&self, tag: i32) -> usize {
+        let count = unsafe { FixTrailer_getGroupCount(self.0, tag) };
+        count.try_into().unwrap_or_default()
+    }
+
+    fn iter_fields(&self) -> FieldIterator<'_> {
+        unsafe { FixTrailer_getFieldIterator(self.0) }
+            .map(FieldIterator::new)
+            .expect("Fail to allocate FieldIterator")
+    }
 }
 
 impl Clone for Trailer {
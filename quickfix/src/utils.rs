@@ -1,9 +1,12 @@
 use std::{
     ffi::{self, CStr},
     ptr::NonNull,
+    slice,
 };
 
-use crate::QuickFixError;
+use quickfix_ffi::{FixSessionID_copy, FixSessionID_t};
+
+use crate::{QuickFixError, SessionId};
 
 #[inline(always)]
 pub fn read_checked_cstr(val: NonNull<ffi::c_char>) -> String {
@@ -11,6 +14,49 @@ pub fn read_checked_cstr(val: NonNull<ffi::c_char>) -> String {
     cstr.to_string_lossy().to_string()
 }
 
+#[inline(always)]
+pub fn read_checked_bytes(val: NonNull<ffi::c_char>, len: u64) -> Vec<u8> {
+    let bytes = unsafe { slice::from_raw_parts(val.as_ptr().cast::<u8>(), len as usize) };
+    bytes.to_vec()
+}
+
+/// Callback for `*_writeBytes` FFI functions: append data to the `Vec<u8>` given as context.
+pub extern "C" fn append_to_vec(ctx: *mut ffi::c_void, data: *const ffi::c_char, len: u64) {
+    let buffer = unsafe { &mut *ctx.cast::<Vec<u8>>() };
+    if !data.is_null() {
+        buffer.extend_from_slice(unsafe { slice::from_raw_parts(data.cast::<u8>(), len as usize) });
+    }
+}
+
+/// Callback for FFI functions listing strings: push data to the `Vec<String>` given as context.
+pub extern "C" fn push_string_to_vec(ctx: *mut ffi::c_void, data: *const ffi::c_char, len: u64) {
+    let values = unsafe { &mut *ctx.cast::<Vec<String>>() };
+    if !data.is_null() {
+        let bytes = unsafe { slice::from_raw_parts(data.cast::<u8>(), len as usize) };
+        values.push(String::from_utf8_lossy(bytes).to_string());
+    }
+}
+
+/// Callback for FFI functions listing integers: push value to the `Vec<i32>` given as context.
+pub extern "C" fn push_int_to_vec(ctx: *mut ffi::c_void, value: i32) {
+    let values = unsafe { &mut *ctx.cast::<Vec<i32>>() };
+    values.push(value);
+}
+
+/// Callback for FFI functions listing sessions: push a copy of the ID to the `Vec<SessionId>` given as context.
+///
+/// Return a non zero code to stop listing when the ID cannot be copied, last error is then already set.
+pub extern "C" fn push_session_id_to_vec(ctx: *mut ffi::c_void, session_id: FixSessionID_t) -> i8 {
+    let values = unsafe { &mut *ctx.cast::<Vec<SessionId>>() };
+    match unsafe { FixSessionID_copy(session_id) } {
+        Some(copy) => {
+            values.push(SessionId(copy));
+            0
+        }
+        None => -1,
+    }
+}
+
 #[inline(always)]
 pub unsafe fn from_ffi_str<'a>(ptr: *const ffi::c_char) -> &'a str {
     assert!(!ptr.is_null(), "null ptr given from `c_str()`");
@@ -0,0 +1,199 @@
+use std::{
+    sync::mpsc::Receiver,
+    thread,
+    time::{Duration, Instant},
+};
+
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+fn event_name(event: &FixEvent) -> String {
+    match event {
+        FixEvent::Create(_) => "Create".to_string(),
+        FixEvent::Logon(_) => "Logon".to_string(),
+        FixEvent::Logout(_) => "Logout".to_string(),
+        FixEvent::ToAdmin(msg, _) => format!("ToAdmin:{}", msg.msg_type().unwrap()),
+        FixEvent::ToApp(msg, _) => format!("ToApp:{}", msg.msg_type().unwrap()),
+        FixEvent::FromAdmin(msg, _) => format!("FromAdmin:{}", msg.msg_type().unwrap()),
+        FixEvent::FromApp(msg, _, _) => format!("FromApp:{}", msg.msg_type().unwrap()),
+    }
+}
+
+/// Wait for an event matching name, and return events received until then.
+fn wait_event(events: &Receiver<FixEvent>, name: &str) -> Vec<String> {
+    let start = Instant::now();
+    let mut names = Vec::new();
+    while !names.iter().any(|x| x == name) {
+        let timeout = Duration::from_secs(10).saturating_sub(start.elapsed());
+        let Ok(event) = events.recv_timeout(timeout) else {
+            panic!("Timeout waiting for {name}, got {names:?}");
+        };
+        names.push(event_name(&event));
+    }
+    names
+}
+
+#[test]
+fn test_channel_events() -> Result<(), QuickFixError> {
+    let (sender, sender_events) = ChannelApplication::new(64);
+    let (receiver, receiver_events) = ChannelApplication::new(64);
+    let receiver = receiver.with_reply_timeout(Duration::from_secs(5));
+
+    // Receiver rejects some messages from another thread.
+    let receiver_thread = thread::spawn(move || {
+        let mut headlines = Vec::new();
+        for event in receiver_events {
+            assert_eq!(
+                event.session_id().to_repr(),
+                ServerType::Receiver.session_id().to_repr()
+            );
+            if let FixEvent::FromApp(msg, _, reply) = event {
+                assert!(reply.is_awaited());
+                let headline = msg.get_field(MSG_HEADLINE).unwrap();
+                reply.send(match headline.as_str() {
+                    "REJECT" => Err(MsgFromAppError::IncorrectTagValue),
+                    "UNSUPPORTED" => Err(MsgFromAppError::UnsupportedMessageType),
+                    _ => Ok(()),
+                });
+                headlines.push(headline);
+            }
+        }
+        headlines
+    });
+
+    let communication_port = find_available_port();
+    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
+    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
+
+    let log_factory = LogFactory::try_new(&NullLogger)?;
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&receiver)?;
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+
+    socket_receiver.start()?;
+    socket_sender.start()?;
+
+    let names = wait_event(&sender_events, "Logon");
+    assert_eq!(names, ["Create", "ToAdmin:A", "FromAdmin:A", "Logon"]);
+
+    // Accepted message.
+    send_to_target(build_news("ACCEPT", &[])?, &ServerType::Sender.session_id())?;
+    assert_eq!(wait_event(&sender_events, "ToApp:B"), ["ToApp:B"]);
+
+    // Rejected message is answered with a session level reject.
+    send_to_target(build_news("REJECT", &[])?, &ServerType::Sender.session_id())?;
+    let names = wait_event(&sender_events, "FromAdmin:3");
+    assert_eq!(names, ["ToApp:B", "FromAdmin:3"]);
+
+    // Unsupported message is answered with a business message reject.
+    send_to_target(
+        build_news("UNSUPPORTED", &[])?,
+        &ServerType::Sender.session_id(),
+    )?;
+    let names = wait_event(&sender_events, "FromApp:j");
+    assert_eq!(names, ["ToApp:B", "FromApp:j"]);
+
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+    assert!(wait_event(&sender_events, "Logout").contains(&"Logout".to_string()));
+    assert_eq!(sender.dropped_count(), 0);
+    assert_eq!(receiver.dropped_count(), 0);
+
+    drop(socket_receiver);
+    drop(app_receiver);
+    drop(receiver);
+    assert_eq!(
+        receiver_thread.join().unwrap(),
+        ["ACCEPT", "REJECT", "UNSUPPORTED"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_channel_backpressure() -> Result<(), QuickFixError> {
+    let session_id = ServerType::Sender.session_id();
+
+    // Events are dropped when channel is full.
+    let (app, events) = ChannelApplication::new(1);
+    let app = app.with_backpressure(BackpressurePolicy::DropEvent);
+    app.on_logon(&session_id);
+    app.on_logout(&session_id);
+    assert_eq!(app.dropped_count(), 1);
+    assert!(matches!(events.try_recv(), Ok(FixEvent::Logon(_))));
+    assert!(events.try_recv().is_err());
+
+    // ... or when receiver is gone.
+    drop(events);
+    app.on_logon(&session_id);
+    assert_eq!(app.dropped_count(), 2);
+
+    // Received messages are accepted when no one replies, or when event is lost.
+    let (app, events) = ChannelApplication::new(1);
+    let app = app.with_reply_timeout(Duration::from_millis(10));
+    let msg = build_news("Hello", &[])?;
+    assert!(app.on_msg_from_app(&msg, &session_id).is_ok());
+    assert!(matches!(
+        events.try_recv(),
+        Ok(FixEvent::FromApp(_, _, reply)) if reply.is_awaited()
+    ));
+    drop(events);
+    assert!(app.on_msg_from_app(&msg, &session_id).is_ok());
+
+    // ... unless they are configured to be rejected.
+    let (app, events) = ChannelApplication::new(1);
+    let app = app
+        .with_reply_timeout(Duration::from_millis(10))
+        .with_reply_timeout_policy(ReplyTimeoutPolicy::Reject(
+            MsgFromAppError::UnsupportedMessageType,
+        ));
+    assert_eq!(
+        app.on_msg_from_app(&msg, &session_id),
+        Err(MsgFromAppError::UnsupportedMessageType)
+    );
+    assert!(events.try_recv().is_ok());
+    drop(events);
+    assert!(app.on_msg_from_app(&msg, &session_id).is_err());
+
+    // Reply is not awaited by default.
+    let (app, events) = ChannelApplication::new(1);
+    assert!(app.on_msg_from_app(&msg, &session_id).is_ok());
+    assert!(matches!(
+        events.try_recv(),
+        Ok(FixEvent::FromApp(_, _, reply)) if !reply.is_awaited()
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "crossbeam-channel")]
+#[test]
+fn test_crossbeam_channel() {
+    let (sender, events) = crossbeam_channel::bounded(1);
+    let app = ChannelApplication::with_crossbeam_sender(sender)
+        .with_backpressure(BackpressurePolicy::DropEvent);
+
+    let session_id = ServerType::Sender.session_id();
+    app.on_create(&session_id);
+    app.on_logon(&session_id);
+    assert_eq!(app.dropped_count(), 1);
+    assert!(matches!(events.try_recv(), Ok(FixEvent::Create(_))));
+}
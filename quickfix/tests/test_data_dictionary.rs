@@ -26,3 +26,207 @@ fn test_build_message() {
     let msg = dd.try_build_message("8=FIX.4.1\u{1}9=65\u{1}35=A\u{1}34=1\u{1}49=SERVER1\u{1}52=20231115-14:02:24\u{1}56=CLIENT1\u{1}98=0\u{1}108=20\u{1}10=035\u{1}").unwrap();
     assert_eq!(msg.to_fix_string().as_deref(), Ok("8=FIX.4.1\u{1}9=65\u{1}35=A\u{1}34=1\u{1}49=SERVER1\u{1}52=20231115-14:02:24\u{1}56=CLIENT1\u{1}98=0\u{1}108=20\u{1}10=035\u{1}"))
 }
+
+const FIXT11_PATH: &str = "../quickfix-ffi/libquickfix/spec/FIXT11.xml";
+const FIX50SP2_PATH: &str = "../quickfix-ffi/libquickfix/spec/FIX50SP2.xml";
+
+const MSG_MD_SNAPSHOT: &str = "8=FIXT.1.1\u{1}9=119\u{1}35=W\u{1}34=2\u{1}49=SENDER\u{1}52=20240105-08:30:00.000\u{1}56=TARGET\u{1}1128=9\u{1}55=IBM\u{1}268=2\u{1}269=0\u{1}270=1.5\u{1}271=100\u{1}269=1\u{1}270=1.6\u{1}271=200\u{1}10=138\u{1}";
+const MSG_MD_SNAPSHOT_NO_APPL_VER_ID: &str = "8=FIXT.1.1\u{1}9=112\u{1}35=W\u{1}34=2\u{1}49=SENDER\u{1}52=20240105-08:30:00.000\u{1}56=TARGET\u{1}55=IBM\u{1}268=2\u{1}269=0\u{1}270=1.5\u{1}271=100\u{1}269=1\u{1}270=1.6\u{1}271=200\u{1}10=064\u{1}";
+const MSG_LOGON: &str = "8=FIXT.1.1\u{1}9=74\u{1}35=A\u{1}34=1\u{1}49=SENDER\u{1}52=20240105-08:30:00.000\u{1}56=TARGET\u{1}98=0\u{1}108=30\u{1}1137=9\u{1}10=003\u{1}";
+
+fn check_md_snapshot_groups(msg: &Message) {
+    assert_eq!(msg.get_field(268).as_deref(), Some("2"));
+    assert_eq!(msg.get_field(270), None);
+
+    let entries: Vec<_> = (1..=2)
+        .map(|index| {
+            let group = msg.clone_group(index, 268).unwrap();
+            (group.get_field(269).unwrap(), group.get_field(270).unwrap())
+        })
+        .collect();
+    assert_eq!(
+        entries,
+        vec![
+            ("0".to_string(), "1.5".to_string()),
+            ("1".to_string(), "1.6".to_string())
+        ]
+    );
+    assert!(msg.clone_group(3, 268).is_none());
+}
+
+#[test]
+fn test_build_app_message() {
+    let transport = DataDictionary::try_from_path(FIXT11_PATH).unwrap();
+    let application = DataDictionary::try_from_path(FIX50SP2_PATH).unwrap();
+
+    let msg = transport
+        .try_build_app_message(&application, MSG_MD_SNAPSHOT)
+        .unwrap();
+    check_md_snapshot_groups(&msg);
+    assert_eq!(msg.with_header(|x| x.get_field(1128)).as_deref(), Some("9"));
+    assert_eq!(msg.to_fix_string().as_deref(), Ok(MSG_MD_SNAPSHOT));
+}
+
+#[test]
+fn test_dictionary_provider() {
+    let mut provider =
+        DataDictionaryProvider::new(DataDictionary::try_from_path(FIXT11_PATH).unwrap());
+    provider.add_application("9", DataDictionary::try_from_path(FIX50SP2_PATH).unwrap());
+
+    // Using ApplVerID from message.
+    let msg = provider.try_build_message(MSG_MD_SNAPSHOT).unwrap();
+    check_md_snapshot_groups(&msg);
+
+    // Admin messages do not require application dictionary.
+    let msg = provider.try_build_message(MSG_LOGON).unwrap();
+    assert_eq!(msg.get_field(1137).as_deref(), Some("9"));
+    assert_eq!(msg.to_fix_string().as_deref(), Ok(MSG_LOGON));
+
+    // Missing ApplVerID.
+    assert_eq!(
+        provider
+            .try_build_message(MSG_MD_SNAPSHOT_NO_APPL_VER_ID)
+            .unwrap_err(),
+        QuickFixError::invalid_argument("Cannot find ApplVerID for application message")
+    );
+
+    // Using default ApplVerID.
+    provider.set_default_appl_ver_id("9");
+    let msg = provider
+        .try_build_message(MSG_MD_SNAPSHOT_NO_APPL_VER_ID)
+        .unwrap();
+    check_md_snapshot_groups(&msg);
+
+    // Data fields content is not mistaken for header fields.
+    let mut news = Message::new();
+    news.with_header_mut(|x| {
+        x.set_field(8, "FIXT.1.1")?;
+        x.set_field(35, "B")
+    })
+    .unwrap();
+    news.set_field(148, "Hello").unwrap();
+    news.set_field(358, 12).unwrap();
+    news.set_field_bytes(359, b"\x011128=7\x0135=A").unwrap();
+    let text = news.to_fix_string().unwrap();
+    let msg = provider.try_build_message(&text).unwrap();
+    assert_eq!(msg.get_field(359).as_deref(), Some("\x011128=7\x0135=A"));
+
+    // Unknown ApplVerID.
+    provider.set_default_appl_ver_id("7");
+    assert_eq!(
+        provider
+            .try_build_message(MSG_MD_SNAPSHOT_NO_APPL_VER_ID)
+            .unwrap_err(),
+        QuickFixError::UnsupportedVersion("No application dictionary for ApplVerID: 7".to_string())
+    );
+}
+
+const FIX44_PATH: &str = "../quickfix-ffi/libquickfix/spec/FIX44.xml";
+
+#[test]
+fn test_field_introspection() {
+    let dd = DataDictionary::try_from_path(FIX44_PATH).unwrap();
+
+    assert_eq!(dd.get_field_name(55).as_deref(), Some("Symbol"));
+    assert_eq!(dd.get_field_name(99999), None);
+    assert_eq!(dd.get_field_tag("Symbol"), Some(55));
+    assert_eq!(dd.get_field_tag("NotAField"), None);
+
+    assert_eq!(dd.get_field_type(55), Some(FieldType::String));
+    assert_eq!(dd.get_field_type(54), Some(FieldType::Char));
+    assert_eq!(dd.get_field_type(38), Some(FieldType::Qty));
+    assert_eq!(dd.get_field_type(60), Some(FieldType::UtcTimeStamp));
+    assert_eq!(dd.get_field_type(99999), None);
+}
+
+#[test]
+fn test_field_values() {
+    let dd = DataDictionary::try_from_path(FIX44_PATH).unwrap();
+
+    assert!(dd.has_field_values(54));
+    assert!(dd.is_field_value(54, "1"));
+    assert!(dd.is_field_value(54, "2"));
+    assert!(!dd.is_field_value(54, "Z"));
+    assert!(!dd.has_field_values(55));
+    assert!(!dd.is_field_value(55, "IBM"));
+
+    let values = dd.get_field_values(54).unwrap();
+    assert!(values.contains(&"1".to_string()));
+    assert!(values.contains(&"2".to_string()));
+    assert!(dd.get_field_values(55).unwrap().is_empty());
+
+    assert_eq!(dd.get_value_name(54, "1").as_deref(), Some("BUY"));
+    assert_eq!(dd.get_value_name(54, "Z"), None);
+    assert_eq!(dd.get_value_name(55, "IBM"), None);
+}
+
+#[test]
+fn test_message_introspection() {
+    let dd = DataDictionary::try_from_path(FIX44_PATH).unwrap();
+
+    for tag in [11, 54, 60, 40] {
+        assert!(dd.is_required_field("D", tag), "{tag} should be required");
+    }
+    assert!(!dd.is_required_field("D", 44));
+    assert!(!dd.is_required_field("ZZZ", 11));
+
+    let required = dd.get_required_fields("D").unwrap();
+    for tag in [11, 54, 60, 40] {
+        assert!(required.contains(&tag), "{tag} should be required");
+    }
+    assert!(!required.contains(&44));
+    assert!(dd.get_required_fields("ZZZ").unwrap().is_empty());
+
+    assert_eq!(dd.get_group_delimiter("D", 453), Some(448));
+    assert_eq!(dd.get_group_delimiter("D", 55), None);
+    assert_eq!(dd.get_group_delimiter("ZZZ", 453), None);
+
+    let fields = dd.get_group_fields("D", 453).unwrap();
+    assert_eq!(&fields[..4], &[448, 447, 452, 802]);
+    assert_eq!(dd.get_group_fields("D", 55), None);
+
+    // Nested group.
+    assert_eq!(dd.get_group_delimiter("D", 802), Some(523));
+    assert_eq!(dd.get_group_fields("D", 802), Some(vec![523, 803]));
+}
+
+#[test]
+fn test_validate() {
+    let dd = DataDictionary::try_from_path(FIX44_PATH).unwrap();
+
+    let valid = Message::try_from_text("8=FIX.4.4\u{1}9=116\u{1}35=D\u{1}34=2\u{1}49=SENDER\u{1}52=20240105-08:30:00.000\u{1}56=TARGET\u{1}11=ID1\u{1}21=1\u{1}38=100\u{1}40=1\u{1}54=1\u{1}55=IBM\u{1}60=20240105-08:30:00\u{1}10=220\u{1}").unwrap();
+    assert_eq!(dd.validate(&valid), Ok(()));
+
+    // Missing required field.
+    let mut msg = valid.clone();
+    msg.remove_field(11).unwrap();
+    let err = dd.validate(&msg).unwrap_err();
+    assert_eq!(err.tag, Some(11));
+    assert_eq!(
+        err.session_reject_reason,
+        Some(SessionRejectReason::RequiredTagMissing)
+    );
+
+    // Invalid enum value.
+    let mut msg = valid.clone();
+    msg.set_field(54, "Z").unwrap();
+    let err = dd.validate(&msg).unwrap_err();
+    assert_eq!(err.tag, Some(54));
+    assert_eq!(
+        err.session_reject_reason,
+        Some(SessionRejectReason::ValueIsIncorrect)
+    );
+
+    // Invalid message type.
+    let mut msg = valid.clone();
+    msg.with_header_mut(|h| h.set_field(35, "ZZZ")).unwrap();
+    let err = dd.validate(&msg).unwrap_err();
+    assert_eq!(
+        err.session_reject_reason,
+        Some(SessionRejectReason::InvalidMsgType)
+    );
+    assert!(matches!(
+        QuickFixError::from(err),
+        QuickFixError::InvalidMessageType(_)
+    ));
+}
@@ -0,0 +1,266 @@
+use std::{thread, time::Duration};
+
+use quickfix::{dictionary_item::*, *};
+use utils::*;
+
+mod utils;
+
+fn new_session_settings(port: u16) -> Result<Dictionary, QuickFixError> {
+    Dictionary::try_from_items(&[
+        &StartTime("00:00:00"),
+        &EndTime("23:59:59"),
+        &HeartBtInt(20),
+        &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
+        &SocketAcceptPort(port),
+        &SocketConnectPort(port),
+        &SocketConnectHost("127.0.0.1"),
+    ])
+}
+
+#[test]
+fn test_add_remove_session() -> Result<(), QuickFixError> {
+    check_add_remove_session(FixSocketServerKind::SingleThreaded)?;
+    check_add_remove_session(FixSocketServerKind::MultiThreaded)?;
+    Ok(())
+}
+
+fn check_add_remove_session(server_kind: FixSocketServerKind) -> Result<(), QuickFixError> {
+    let communication_port = find_available_port();
+    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
+
+    // Initiator tries to connect new sessions on each reconnection attempt.
+    let mut settings_sender = SessionSettings::new();
+    settings_sender.set(
+        None,
+        Dictionary::try_from_items(&[&ConnectionType::Initiator, &ReconnectInterval(1)])?,
+    )?;
+    settings_sender.set(
+        Some(&ServerType::Sender.session_id()),
+        new_session_settings(communication_port)?,
+    )?;
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let log_factory = LogFactory::try_new(&StdLogger::Stdout)?;
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app,
+        &message_store_factory_sender,
+        &log_factory,
+        server_kind,
+    )?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app,
+        &message_store_factory_receiver,
+        &log_factory,
+        server_kind,
+    )?;
+
+    socket_receiver.start()?;
+    socket_sender.start()?;
+    wait_until(|| socket_sender.is_logged_on().unwrap());
+
+    // Add a new counterparty on both sides.
+    let sender_id = SessionId::try_new("FIX.4.4", "SENDER2", "RECEIVER", "")?;
+    let receiver_id = SessionId::try_new("FIX.4.4", "RECEIVER", "SENDER2", "")?;
+
+    socket_receiver.add_session(
+        receiver_id.clone(),
+        new_session_settings(communication_port)?,
+    )?;
+    socket_sender.add_session(sender_id.clone(), new_session_settings(communication_port)?)?;
+    assert_eq!(socket_sender.session_ids()?.len(), 2);
+    assert_eq!(socket_receiver.session_ids()?.len(), 2);
+
+    wait_until(|| {
+        socket_receiver
+            .session(receiver_id.clone())
+            .unwrap()
+            .is_logged_on()
+            .unwrap()
+    });
+    let mut session = socket_sender.session(ServerType::Sender.session_id())?;
+    assert!(session.is_logged_on()?);
+
+    // Invalid changes.
+    assert!(matches!(
+        socket_sender.add_session(sender_id.clone(), new_session_settings(communication_port)?),
+        Err(QuickFixError::ConfigError(_))
+    ));
+    assert!(matches!(
+        socket_receiver.add_session(
+            SessionId::try_new("FIX.4.4", "RECEIVER", "SENDER3", "")?,
+            new_session_settings(find_available_port())?,
+        ),
+        Err(QuickFixError::ConfigError(_))
+    ));
+    assert!(matches!(
+        socket_receiver.add_session(
+            SessionId::try_new("FIX.4.4", "RECEIVER", "SENDER3", "")?,
+            Dictionary::try_from_items(&[&ConnectionType::Initiator])?,
+        ),
+        Err(QuickFixError::ConfigError(_))
+    ));
+    assert_eq!(socket_receiver.session_ids()?.len(), 2);
+
+    // Remove new counterparty, others sessions stay connected.
+    socket_sender.remove_session(&sender_id)?;
+    wait_until(|| {
+        !socket_receiver
+            .session(receiver_id.clone())
+            .unwrap()
+            .is_logged_on()
+            .unwrap()
+    });
+    socket_receiver.remove_session(&receiver_id)?;
+
+    assert!(matches!(
+        socket_receiver.remove_session(&receiver_id),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+    assert!(socket_sender.session(sender_id.clone()).is_err());
+    assert_eq!(socket_sender.session_ids()?.len(), 1);
+    assert_eq!(socket_receiver.session_ids()?.len(), 1);
+
+    // Removed sessions cannot be reached anymore.
+    assert!(unsafe { Session::lookup(&sender_id) }.is_err());
+    assert!(matches!(
+        send_to_target(Message::new(), &receiver_id),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+
+    // Same counterparty can be added back.
+    socket_receiver.add_session(
+        receiver_id.clone(),
+        new_session_settings(communication_port)?,
+    )?;
+    socket_sender.add_session(sender_id.clone(), new_session_settings(communication_port)?)?;
+    wait_until(|| {
+        socket_receiver
+            .session(receiver_id.clone())
+            .unwrap()
+            .is_logged_on()
+            .unwrap()
+    });
+
+    let mut session = socket_sender.session(ServerType::Sender.session_id())?;
+    assert!(session.is_logged_on()?);
+
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+
+    Ok(())
+}
+
+struct RejectCounterparty(&'static str);
+
+impl ApplicationCallback for RejectCounterparty {
+    fn on_dynamic_session(&self, session: &SessionId) -> bool {
+        session.get_target_comp_id().unwrap() != self.0
+    }
+}
+
+#[test]
+fn test_acceptor_template() -> Result<(), QuickFixError> {
+    check_acceptor_template(FixSocketServerKind::SingleThreaded)?;
+    check_acceptor_template(FixSocketServerKind::MultiThreaded)?;
+    Ok(())
+}
+
+fn check_acceptor_template(server_kind: FixSocketServerKind) -> Result<(), QuickFixError> {
+    let communication_port = find_available_port();
+    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
+
+    let mut settings_sender = SessionSettings::new();
+    settings_sender.set(
+        None,
+        // Counterparties do not retry their logon before the end of the test.
+        Dictionary::try_from_items(&[&ConnectionType::Initiator, &ReconnectInterval(60)])?,
+    )?;
+    for sender in ["CLIENT1", "CLIENT2", "OTHER"] {
+        settings_sender.set(
+            Some(&SessionId::try_new("FIX.4.4", sender, "RECEIVER", "")?),
+            new_session_settings(communication_port)?,
+        )?;
+    }
+
+    let callbacks_receiver = RejectCounterparty("CLIENT2");
+    let app_receiver = Application::try_new(&callbacks_receiver)?;
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let log_factory = LogFactory::try_new(&StdLogger::Stdout)?;
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        &log_factory,
+        server_kind,
+    )?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        &log_factory,
+        server_kind,
+    )?;
+
+    // Invalid templates.
+    assert!(matches!(
+        socket_receiver.add_template(
+            SessionId::try_new("FIX.4.4", "RECEIVER", "*", "")?,
+            Dictionary::try_from_items(&[&ConnectionType::Initiator])?,
+        ),
+        Err(QuickFixError::ConfigError(_))
+    ));
+    assert!(matches!(
+        socket_receiver.add_template(
+            SessionId::try_new("FIX.4.4", "RECEIVER", "*", "")?,
+            Dictionary::new(),
+        ),
+        Err(QuickFixError::ConfigError(_))
+    ));
+
+    socket_receiver.add_template(
+        SessionId::try_new("FIX.4.4", "RECEIVER", "CLIENT*", "")?,
+        new_session_settings(communication_port)?,
+    )?;
+
+    socket_receiver.start()?;
+    socket_sender.start()?;
+
+    // Matching counterparty is accepted on its first logon attempt.
+    let client_id = SessionId::try_new("FIX.4.4", "RECEIVER", "CLIENT1", "")?;
+    wait_until(|| {
+        socket_receiver
+            .session(client_id.clone())
+            .is_ok_and(|mut session| session.is_logged_on().unwrap())
+    });
+    let mut session = unsafe { Session::lookup(&client_id) }?;
+    assert!(session.is_logged_on()?);
+    assert!(session.is_acceptor()?);
+
+    // Rejected and unmatched counterparties are never created.
+    thread::sleep(Duration::from_secs(2));
+    let session_ids: Vec<_> = socket_receiver
+        .session_ids()?
+        .iter()
+        .map(|session_id| session_id.to_repr())
+        .collect();
+    assert_eq!(
+        session_ids,
+        [
+            client_id.to_repr(),
+            ServerType::Receiver.session_id().to_repr()
+        ]
+    );
+
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use quickfix::*;
+
+use crate::utils::*;
+
+mod utils;
+
+fn collect_fields<T: FieldMap>(item: &T) -> Vec<(i32, String)> {
+    item.iter_fields().collect()
+}
+
+fn fields(input: &[(i32, &str)]) -> Vec<(i32, String)> {
+    input
+        .iter()
+        .map(|(tag, value)| (*tag, value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_iter_empty() {
+    assert_eq!(collect_fields(&Message::new()), vec![]);
+    assert_eq!(collect_fields(&Header::new()), vec![]);
+    assert_eq!(collect_fields(&Trailer::new()), vec![]);
+    assert_eq!(collect_fields(&Group::try_new(42, 10).unwrap()), vec![]);
+}
+
+#[test]
+fn test_iter_simple_fields() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.set_field(56, "bar")?;
+    msg.set_field(42, "foo")?;
+    msg.set_field(50000, "user defined")?;
+
+    assert_eq!(
+        collect_fields(&msg),
+        fields(&[(42, "foo"), (56, "bar"), (50000, "user defined")])
+    );
+
+    // Iterator can be stopped and restarted.
+    assert_eq!(msg.iter_fields().next(), Some((42, "foo".to_string())));
+    assert_eq!(msg.iter_fields().count(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_iter_header_and_trailer() -> Result<(), QuickFixError> {
+    let mut msg = build_news("Hello", &[])?;
+    msg.with_trailer_mut(|t| t.set_field(93, 4))?;
+
+    assert_eq!(msg.with_header(collect_fields), fields(&[(MSG_TYPE, "B")]));
+    assert_eq!(
+        collect_fields(&msg),
+        fields(&[(MSG_NO_LINES_OF_TEXT, "0"), (MSG_HEADLINE, "Hello")])
+    );
+    assert_eq!(msg.with_trailer(collect_fields), fields(&[(93, "4")]));
+
+    Ok(())
+}
+
+#[test]
+fn test_iter_groups() -> Result<(), QuickFixError> {
+    let msg = build_list_status(
+        "foo",
+        &[
+            &[(11, "a1"), (14, "b1"), (84, "c1"), (8, "e1"), (6, "d1")],
+            &[(84, "c2"), (6, "d2"), (11, "a2"), (14, "b2")],
+        ],
+    )?;
+
+    assert_eq!(
+        collect_fields(&msg),
+        fields(&[
+            (66, "foo"),
+            (73, "2"),
+            (11, "a1"),
+            (14, "b1"),
+            (84, "c1"),
+            (6, "d1"),
+            (8, "e1"),
+            (11, "a2"),
+            (14, "b2"),
+            (84, "c2"),
+            (6, "d2"),
+        ])
+    );
+
+    // Check cloned group only contains its own fields.
+    let group = msg.clone_group(2, 73).unwrap();
+    assert_eq!(
+        collect_fields(&group),
+        fields(&[(11, "a2"), (14, "b2"), (84, "c2"), (6, "d2")])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_iter_nested_groups() -> Result<(), QuickFixError> {
+    let mut group = Group::try_with_orders(100, 101, &[101, 102, 103])?;
+    group.set_field(101, "v1")?;
+    group.add_group(&{
+        let mut sub = Group::try_with_orders(200, 201, &[201, 202])?;
+        sub.set_field(201, "x1")?;
+        sub
+    })?;
+    group.add_group(&{
+        let mut sub = Group::try_with_orders(200, 201, &[201, 202])?;
+        sub.set_field(202, "y2")?;
+        sub.set_field(201, "x2")?;
+        sub
+    })?;
+    group.set_field(103, "v2")?;
+
+    let mut msg = Message::new();
+    msg.set_field(58, "text")?;
+    msg.add_group(&group)?;
+
+    assert_eq!(
+        collect_fields(&msg),
+        fields(&[
+            (58, "text"),
+            (100, "1"),
+            (101, "v1"),
+            (103, "v2"),
+            (200, "2"),
+            (201, "x1"),
+            (201, "x2"),
+            (202, "y2"),
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_iter_group_position() -> Result<(), QuickFixError> {
+    let mut group = Group::try_with_orders(100, 101, &[101, 102])?;
+    group.set_field(101, "v1")?;
+    group.add_group(&{
+        let mut sub = Group::try_with_orders(200, 201, &[201, 202])?;
+        sub.set_field(201, "x1")?;
+        sub.set_field(202, "y1")?;
+        sub
+    })?;
+
+    let mut msg = Message::new();
+    msg.set_field(58, "text")?;
+    msg.add_group(&group)?;
+    msg.add_group(&group)?;
+
+    let mut positions = Vec::new();
+    let mut iter = msg.iter_fields();
+    while let Some((tag, _value)) = iter.next() {
+        positions.push((tag, iter.depth(), iter.is_group_start()));
+    }
+
+    assert_eq!(
+        positions,
+        vec![
+            (58, 0, false),
+            (100, 0, false),
+            (101, 1, true),
+            (200, 1, false),
+            (201, 2, true),
+            (202, 2, false),
+            (101, 1, true),
+            (200, 1, false),
+            (201, 2, true),
+            (202, 2, false),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_iter_parsed_message() -> Result<(), QuickFixError> {
+    let msg = Message::try_from_text("9=14\u{1}56=bar\u{1}42=foo\u{1}10=162\u{1}")?;
+
+    // Header and trailer fields are not part of the message body.
+    assert_eq!(collect_fields(&msg), fields(&[(42, "foo")]));
+    assert_eq!(
+        msg.with_header(collect_fields),
+        fields(&[(9, "14"), (56, "bar")])
+    );
+    assert_eq!(msg.with_trailer(collect_fields), fields(&[(10, "162")]));
+
+    Ok(())
+}
+
+#[test]
+fn test_iter_binary_fields() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.set_field(95, 6)?;
+    msg.set_field_bytes(96, b"a\0b\x01\xff")?;
+
+    let mut iter = msg.iter_fields();
+    assert_eq!(iter.next(), Some((95, "6".to_string())));
+    assert_eq!(iter.value_bytes(), b"6");
+
+    // Value is not truncated, invalid UTF-8 is replaced.
+    assert_eq!(iter.next(), Some((96, "a\0b\u{1}\u{fffd}".to_string())));
+    assert_eq!(iter.value_bytes(), b"a\0b\x01\xff");
+    assert_eq!(iter.next(), None);
+
+    Ok(())
+}
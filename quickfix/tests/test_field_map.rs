@@ -43,7 +43,15 @@ fn check_field_map<T: FieldMap>(mut item: T) {
     );
     assert_eq!(item.get_field(FIELD_ID).as_deref(), Some("bar"));
 
+    // Set raw bytes and check
+    item.set_field_bytes(FIELD_ID, b"a\0b\x01c").unwrap();
+    assert_eq!(
+        item.get_field_bytes(FIELD_ID).as_deref(),
+        Some(&b"a\0b\x01c"[..])
+    );
+
     // Remove and check
     item.remove_field(FIELD_ID).unwrap();
     assert_eq!(item.get_field(FIELD_ID), None);
+    assert_eq!(item.get_field_bytes(FIELD_ID), None);
 }
@@ -0,0 +1,223 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use quickfix::*;
+
+fn date(year: u16, month: u8, day: u8) -> UtcDateOnly {
+    UtcDateOnly { year, month, day }
+}
+
+fn time(
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    precision: TimePrecision,
+) -> UtcTimeOnly {
+    UtcTimeOnly {
+        hour,
+        minute,
+        second,
+        nanosecond,
+        precision,
+    }
+}
+
+#[test]
+fn test_primitives() {
+    assert_eq!(i32::from_fix_value("-42"), Some(-42));
+    assert_eq!(u64::from_fix_value("-42"), None);
+    assert_eq!(f64::from_fix_value("12.5"), Some(12.5));
+    assert_eq!(f64::from_fix_value("abc"), None);
+    assert_eq!(String::from_fix_value("hello"), Some("hello".to_string()));
+
+    assert_eq!(bool::from_fix_value("Y"), Some(true));
+    assert_eq!(bool::from_fix_value("N"), Some(false));
+    assert_eq!(bool::from_fix_value("true"), None);
+
+    assert_eq!(char::from_fix_value("1"), Some('1'));
+    assert_eq!(char::from_fix_value("12"), None);
+    assert_eq!(char::from_fix_value(""), None);
+}
+
+#[test]
+fn test_multiple_value() {
+    assert_eq!(
+        Vec::<String>::from_fix_value("A B C"),
+        Some(vec!["A".to_string(), "B".to_string(), "C".to_string()])
+    );
+    assert_eq!(Vec::<char>::from_fix_value("1 2"), Some(vec!['1', '2']));
+    assert_eq!(Vec::<char>::from_fix_value("1 23"), None);
+}
+
+#[test]
+fn test_utc_date_only() {
+    assert_eq!(
+        UtcDateOnly::from_fix_value("20240229"),
+        Some(date(2024, 2, 29))
+    );
+    assert_eq!(UtcDateOnly::from_fix_value("2024022"), None);
+    assert_eq!(UtcDateOnly::from_fix_value("20241301"), None);
+    assert_eq!(UtcDateOnly::from_fix_value("20240231"), None);
+    assert_eq!(UtcDateOnly::from_fix_value("20230229"), None);
+    assert_eq!(UtcDateOnly::from_fix_value("20240431"), None);
+    assert_eq!(UtcDateOnly::from_fix_value("19000229"), None);
+    assert_eq!(
+        UtcDateOnly::from_fix_value("20000229"),
+        Some(date(2000, 2, 29))
+    );
+    assert_eq!(UtcDateOnly::from_fix_value("2024-2-1"), None);
+    assert_eq!(date(2024, 2, 1).to_string(), "20240201");
+}
+
+#[test]
+fn test_utc_time_only() {
+    assert_eq!(
+        UtcTimeOnly::from_fix_value("13:45:01"),
+        Some(time(13, 45, 1, 0, TimePrecision::Seconds))
+    );
+    assert_eq!(
+        UtcTimeOnly::from_fix_value("13:45:01.123"),
+        Some(time(13, 45, 1, 123_000_000, TimePrecision::Milliseconds))
+    );
+    assert_eq!(
+        UtcTimeOnly::from_fix_value("13:45:01.123456"),
+        Some(time(13, 45, 1, 123_456_000, TimePrecision::Microseconds))
+    );
+    assert_eq!(
+        UtcTimeOnly::from_fix_value("13:45:01.123456789"),
+        Some(time(13, 45, 1, 123_456_789, TimePrecision::Nanoseconds))
+    );
+
+    assert_eq!(UtcTimeOnly::from_fix_value("13:45:01."), None);
+    assert_eq!(UtcTimeOnly::from_fix_value("13:45:01.12"), None);
+    assert_eq!(UtcTimeOnly::from_fix_value("24:00:00"), None);
+    assert_eq!(UtcTimeOnly::from_fix_value("13-45-01"), None);
+    assert_eq!(UtcTimeOnly::from_fix_value("0é:45:0"), None);
+
+    for text in [
+        "13:45:01",
+        "13:45:01.001",
+        "13:45:01.000123",
+        "13:45:01.000000123",
+    ] {
+        assert_eq!(UtcTimeOnly::from_fix_value(text).unwrap().to_string(), text);
+    }
+}
+
+#[test]
+fn test_utc_timestamp() {
+    let value = UtcTimestamp::from_fix_value("20240105-08:30:00.250").unwrap();
+    assert_eq!(
+        value,
+        UtcTimestamp {
+            date: date(2024, 1, 5),
+            time: time(8, 30, 0, 250_000_000, TimePrecision::Milliseconds),
+        }
+    );
+    assert_eq!(value.to_string(), "20240105-08:30:00.250");
+
+    assert_eq!(UtcTimestamp::from_fix_value("20240105"), None);
+    assert_eq!(UtcTimestamp::from_fix_value("20240105 08:30:00"), None);
+}
+
+#[test]
+fn test_utc_timestamp_system_time() {
+    let value = UtcTimestamp::from_fix_value("20240105-08:30:00.250").unwrap();
+    let instant = SystemTime::from(value);
+    assert_eq!(
+        instant,
+        UNIX_EPOCH + Duration::from_millis(1_704_443_400_250)
+    );
+
+    // Leap years and dates before epoch.
+    for (text, seconds) in [
+        ("20240229-23:59:59", 1_709_251_199),
+        ("19691231-23:59:59", -1),
+        ("16000301-00:00:00", -11_670_912_000),
+    ] {
+        let value = UtcTimestamp::from_fix_value(text).unwrap();
+        let since_epoch = Duration::from_secs(i64::unsigned_abs(seconds));
+        let expected = if seconds >= 0 {
+            UNIX_EPOCH + since_epoch
+        } else {
+            UNIX_EPOCH - since_epoch
+        };
+        assert_eq!(SystemTime::from(value), expected, "{text}");
+    }
+}
+
+#[test]
+fn test_month_year() {
+    for (text, detail) in [
+        ("202403", None),
+        ("20240315", Some(MonthYearDetail::Day(15))),
+        ("202403w2", Some(MonthYearDetail::Week(2))),
+    ] {
+        let value = MonthYear::from_fix_value(text).unwrap();
+        assert_eq!(
+            value,
+            MonthYear {
+                year: 2024,
+                month: 3,
+                detail
+            }
+        );
+        assert_eq!(value.to_string(), text);
+    }
+
+    assert_eq!(MonthYear::from_fix_value("202413"), None);
+    assert_eq!(MonthYear::from_fix_value("202403w6"), None);
+    assert_eq!(MonthYear::from_fix_value("2024031"), None);
+    assert_eq!(MonthYear::from_fix_value("20240230"), None);
+}
+
+#[test]
+fn test_get_field_as() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.set_field(34, 42)?;
+    msg.set_field(43, true)?;
+    msg.set_field(54, '1')?;
+    msg.set_field(
+        60,
+        UtcTimestamp {
+            date: date(2024, 1, 5),
+            time: time(8, 30, 0, 123_456, TimePrecision::Microseconds),
+        },
+    )?;
+    msg.set_field(200, "202403")?;
+    msg.set_field(18, "1 G")?;
+
+    assert_eq!(msg.get_field_as::<u32>(34)?, Some(42));
+    assert_eq!(msg.get_field_as::<bool>(43)?, Some(true));
+    assert_eq!(msg.get_field_as::<char>(54)?, Some('1'));
+    assert_eq!(
+        msg.get_field(60).as_deref(),
+        Some("20240105-08:30:00.000123")
+    );
+    assert_eq!(
+        msg.get_field_as::<UtcTimestamp>(60)?
+            .map(|x| x.time.nanosecond),
+        Some(123_000)
+    );
+    assert_eq!(
+        msg.get_field_as::<MonthYear>(200)?.map(|x| x.month),
+        Some(3)
+    );
+    assert_eq!(
+        msg.get_field_as::<Vec<String>>(18)?,
+        Some(vec!["1".to_string(), "G".to_string()])
+    );
+
+    // Missing field.
+    assert_eq!(msg.get_field_as::<u32>(999)?, None);
+
+    // Invalid value.
+    assert_eq!(
+        msg.get_field_as::<bool>(34),
+        Err(QuickFixError::FieldConvertError(
+            "Cannot convert field 34 with value \"42\" to bool".to_string()
+        ))
+    );
+
+    Ok(())
+}
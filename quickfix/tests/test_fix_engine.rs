@@ -0,0 +1,104 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+#[derive(Default)]
+struct LogonCounter(AtomicUsize);
+
+impl LogonCounter {
+    fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+impl ApplicationCallback for LogonCounter {
+    fn on_logon(&self, _session: &SessionId) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+type Engine = FixEngine<LogonCounter, NullLogger, MemoryMessageStoreFactory>;
+
+/// Engines are returned from a function: nothing is borrowed from its stack.
+fn build_engines(port: u16) -> Result<(Engine, Engine), QuickFixError> {
+    let acceptor = FixEngine::try_new_acceptor(
+        build_settings(ServerType::Receiver, port)?,
+        Arc::new(LogonCounter::default()),
+        MemoryMessageStoreFactory::new(),
+        Arc::new(NullLogger),
+        FixSocketServerKind::SingleThreaded,
+    )?;
+    let initiator = FixEngine::try_new_initiator(
+        build_settings(ServerType::Sender, port)?,
+        Arc::new(LogonCounter::default()),
+        MemoryMessageStoreFactory::new(),
+        Arc::from(Box::new(NullLogger)),
+        FixSocketServerKind::MultiThreaded,
+    )?;
+    Ok((acceptor, initiator))
+}
+
+#[test]
+fn test_owned_callbacks() -> Result<(), QuickFixError> {
+    let callbacks = Arc::new(LogonCounter::default());
+    let app = Application::try_new_owned(Arc::clone(&callbacks))?;
+    assert_eq!(Arc::strong_count(&callbacks), 2);
+    drop(app);
+    assert_eq!(Arc::strong_count(&callbacks), 1);
+
+    let _app = Application::try_new_owned(Arc::<NullFixApplication>::from(Box::new(
+        NullFixApplication,
+    )))?;
+    let _log_factory = LogFactory::try_new_owned(Arc::new(StdLogger::Stdout))?;
+    Ok(())
+}
+
+#[test]
+fn test_fix_engine() -> Result<(), QuickFixError> {
+    let (mut acceptor, initiator) = build_engines(find_available_port())?;
+    assert!(acceptor.is_acceptor());
+    assert!(!initiator.is_acceptor());
+    assert_eq!(
+        initiator.settings().session_ids()?[0].to_repr(),
+        ServerType::Sender.session_id().to_repr()
+    );
+
+    acceptor.start()?;
+
+    // Engine can be moved to another thread and back.
+    let initiator = thread::spawn(move || -> Result<Engine, QuickFixError> {
+        let mut initiator = initiator;
+        initiator.start()?;
+        wait_until(|| initiator.is_logged_on().unwrap());
+        Ok(initiator)
+    })
+    .join()
+    .unwrap()?;
+
+    wait_until(|| acceptor.is_logged_on().unwrap());
+    assert_eq!(initiator.callbacks().count(), 1);
+    assert_eq!(acceptor.callbacks().count(), 1);
+
+    let session_ids = initiator.session_ids()?;
+    assert_eq!(session_ids.len(), 1);
+    assert!(initiator.session(session_ids[0].clone())?.is_logged_on()?);
+    assert_eq!(acceptor.sessions()?.len(), 1);
+
+    // Dropping engine stops it.
+    drop(initiator);
+    wait_until(|| !acceptor.is_logged_on().unwrap());
+
+    acceptor.stop()?;
+    assert!(acceptor.is_stopped()?);
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use std::sync::Mutex;
+
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+const MSG_TYPE_LOGOUT: &str = "5";
+const MSG_TYPE_TEST_REQUEST: &str = "1";
+const TAG_TEXT: i32 = 58;
+
+/// Acceptor side: only accept a single user.
+#[derive(Default)]
+struct Authenticator {
+    requests: Mutex<Vec<LogonCredentials>>,
+}
+
+impl ApplicationCallback for Authenticator {
+    fn on_logon_request(
+        &self,
+        credentials: &LogonCredentials,
+        _session: &SessionId,
+    ) -> Result<(), LogonRequestError> {
+        self.requests.lock().unwrap().push(credentials.clone());
+
+        if *credentials == LogonCredentials::new("alice", "secret") {
+            Ok(())
+        } else {
+            Err(LogonRequestError::RejectLogon(format!(
+                "Invalid credentials for {}",
+                credentials.username.as_deref().unwrap_or_default()
+            )))
+        }
+    }
+}
+
+/// Initiator side: inject credentials, and never send test requests.
+struct Client {
+    credentials: LogonCredentials,
+    logout_texts: Mutex<Vec<String>>,
+}
+
+impl Client {
+    fn new(credentials: LogonCredentials) -> Self {
+        Self {
+            credentials,
+            logout_texts: Mutex::default(),
+        }
+    }
+}
+
+impl ApplicationCallback for Client {
+    fn on_logon_outgoing(&self, credentials: &mut LogonCredentials, _session: &SessionId) {
+        assert_eq!(*credentials, LogonCredentials::default());
+        *credentials = self.credentials.clone();
+    }
+
+    fn on_admin_outgoing(
+        &self,
+        msg: &Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgToAdminError> {
+        match msg.msg_type().as_deref() {
+            Some(MSG_TYPE_TEST_REQUEST) => Err(MsgToAdminError::DoNotSend),
+            _ => Ok(()),
+        }
+    }
+
+    fn on_msg_from_admin(
+        &self,
+        msg: &Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        if msg.msg_type().as_deref() == Some(MSG_TYPE_LOGOUT) {
+            let text = msg.get_field(TAG_TEXT).unwrap_or_default();
+            self.logout_texts.lock().unwrap().push(text);
+        }
+        Ok(())
+    }
+}
+
+/// Run client against authenticator until condition is true.
+fn run_logon<F>(client: &Client, authenticator: &Authenticator, mut condition: F)
+where
+    F: FnMut(&Initiator<Client, NullLogger, MemoryMessageStoreFactory>) -> bool,
+{
+    let communication_port = find_available_port();
+    let settings_sender = build_settings(ServerType::Sender, communication_port).unwrap();
+    let settings_receiver = build_settings(ServerType::Receiver, communication_port).unwrap();
+
+    let log_factory = LogFactory::try_new(&NullLogger).unwrap();
+    let app_sender = Application::try_new(client).unwrap();
+    let app_receiver = Application::try_new(authenticator).unwrap();
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )
+    .unwrap();
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )
+    .unwrap();
+
+    socket_receiver.start().unwrap();
+    socket_sender.start().unwrap();
+    wait_until(|| condition(&socket_sender));
+    socket_sender.stop().unwrap();
+    socket_receiver.stop().unwrap();
+}
+
+#[test]
+fn test_logon_accepted() {
+    let client = Client::new(LogonCredentials::new("alice", "secret"));
+    let authenticator = Authenticator::default();
+
+    run_logon(&client, &authenticator, |socket_sender| {
+        if !socket_sender.is_logged_on().unwrap() {
+            return false;
+        }
+
+        // Vetoed admin messages are neither sent nor stored.
+        let mut session = socket_sender
+            .session(ServerType::Sender.session_id())
+            .unwrap();
+        let next_seq_num = session.next_sender_msg_seq_num().unwrap();
+        assert!(!session.send_test_request("PING").unwrap());
+        assert_eq!(session.next_sender_msg_seq_num().unwrap(), next_seq_num);
+        true
+    });
+
+    assert_eq!(
+        *authenticator.requests.lock().unwrap(),
+        [LogonCredentials::new("alice", "secret")]
+    );
+    // Only logout is the one from engine shutdown.
+    assert!(!client
+        .logout_texts
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|text| text.contains("Invalid credentials")));
+}
+
+#[test]
+fn test_logon_rejected() {
+    let client = Client::new(LogonCredentials {
+        username: Some("mallory".to_string()),
+        password: None,
+    });
+    let authenticator = Authenticator::default();
+
+    run_logon(&client, &authenticator, |_| {
+        !client.logout_texts.lock().unwrap().is_empty()
+    });
+
+    assert_eq!(
+        authenticator.requests.lock().unwrap()[0],
+        LogonCredentials {
+            username: Some("mallory".to_string()),
+            password: None,
+        }
+    );
+    let logout_texts = client.logout_texts.lock().unwrap();
+    assert!(
+        logout_texts[0].contains("Invalid credentials for mallory"),
+        "{logout_texts:?}"
+    );
+}
+
+#[test]
+fn test_logon_credentials_debug() {
+    let credentials = LogonCredentials::new("alice", "secret");
+    assert_eq!(
+        format!("{credentials:?}"),
+        r#"LogonCredentials { username: Some("alice"), password: Some("***") }"#
+    );
+}
@@ -0,0 +1,139 @@
+use quickfix::*;
+
+use crate::utils::*;
+
+mod utils;
+
+const FIX44_PATH: &str = "../quickfix-ffi/libquickfix/spec/FIX44.xml";
+
+fn build_message() -> Result<Message, QuickFixError> {
+    let mut msg = build_list_status(
+        "foo",
+        &[&[(11, "a1"), (14, "b1")], &[(11, "a2"), (84, "c2")]],
+    )?;
+    msg.with_header_mut(|h| -> Result<(), QuickFixError> {
+        h.set_field(8, "FIX.4.4")?;
+        h.set_field(MSG_TYPE, "N")
+    })?;
+    msg.set_field(54, "1")?;
+    msg.set_field(50000, "custom")?;
+    Ok(msg)
+}
+
+#[test]
+fn test_single_line() -> Result<(), QuickFixError> {
+    let dictionary = DataDictionary::try_from_path(FIX44_PATH)?;
+    let msg = build_message()?;
+
+    assert_eq!(
+        MessageFormatter::new(&dictionary).format(&msg),
+        "BeginString(8)=FIX.4.4 | MsgType(35)=N (ListStatus) \
+         | Side(54)=1 (BUY) | ListID(66)=foo | NoOrders(73)=2 \
+         [ClOrdID(11)=a1 | CumQty(14)=b1] [ClOrdID(11)=a2 | CxlQty(84)=c2] \
+         | 50000=custom"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_multi_line() -> Result<(), QuickFixError> {
+    let dictionary = DataDictionary::try_from_path(FIX44_PATH)?;
+    let msg = build_message()?;
+
+    assert_eq!(
+        MessageFormatter::new(&dictionary)
+            .with_layout(FormatLayout::MultiLine)
+            .format(&msg),
+        "BeginString(8)=FIX.4.4\n\
+         MsgType(35)=N (ListStatus)\n\
+         Side(54)=1 (BUY)\n\
+         ListID(66)=foo\n\
+         NoOrders(73)=2\n  \
+           - ClOrdID(11)=a1\n    \
+             CumQty(14)=b1\n  \
+           - ClOrdID(11)=a2\n    \
+             CxlQty(84)=c2\n\
+         50000=custom"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_nested_groups() -> Result<(), QuickFixError> {
+    let dictionary = DataDictionary::try_from_path(FIX44_PATH)?;
+
+    // NoSides > NoPartyIDs > NoPartySubIDs
+    let mut side = Group::try_with_orders(552, 54, &[54, 453])?;
+    side.set_field(54, "2")?;
+    side.add_group(&{
+        let mut party = Group::try_with_orders(453, 448, &[448, 802])?;
+        party.set_field(448, "P1")?;
+        party.add_group(&{
+            let mut sub = Group::try_with_orders(802, 523, &[523])?;
+            sub.set_field(523, "S1")?;
+            sub
+        })?;
+        party
+    })?;
+
+    let mut msg = Message::new();
+    msg.add_group(&side)?;
+    msg.set_field(55, "IBM")?;
+
+    let formatter = MessageFormatter::new(&dictionary);
+    assert_eq!(
+        formatter.format(&msg),
+        "Symbol(55)=IBM | NoSides(552)=1 (ONE_SIDE) [Side(54)=2 (SELL) | NoPartyIDs(453)=1 \
+         [PartyID(448)=P1 | NoPartySubIDs(802)=1 [PartySubID(523)=S1]]]"
+    );
+    assert_eq!(
+        formatter.with_layout(FormatLayout::MultiLine).format(&msg),
+        "Symbol(55)=IBM\n\
+         NoSides(552)=1 (ONE_SIDE)\n  \
+           - Side(54)=2 (SELL)\n    \
+             NoPartyIDs(453)=1\n      \
+               - PartyID(448)=P1\n        \
+                 NoPartySubIDs(802)=1\n          \
+                   - PartySubID(523)=S1"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_format_fields() -> Result<(), QuickFixError> {
+    let dictionary = DataDictionary::try_from_path(FIX44_PATH)?;
+    let msg = build_message()?;
+
+    let formatter = MessageFormatter::new(&dictionary);
+    assert_eq!(
+        msg.with_header(|h| formatter.format_fields(h)),
+        "BeginString(8)=FIX.4.4 | MsgType(35)=N (ListStatus)"
+    );
+    assert_eq!(formatter.format(&Message::new()), "");
+    assert_eq!(
+        formatter.format_fields(&msg.clone_group(2, 73).unwrap()),
+        "ClOrdID(11)=a2 | CxlQty(84)=c2"
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "quickfix-spec-parser")]
+#[test]
+fn test_format_with_spec() -> Result<(), QuickFixError> {
+    let spec = quickfix_spec_parser::parse_spec(&std::fs::read(FIX44_PATH).unwrap()).unwrap();
+    let msg = build_message()?;
+
+    assert_eq!(
+        MessageFormatter::new(&spec).format(&msg),
+        "BeginString(8)=FIX.4.4 | MsgType(35)=N (LIST_STATUS) \
+         | Side(54)=1 (BUY) | ListID(66)=foo | NoOrders(73)=2 \
+         [ClOrdID(11)=a1 | CumQty(14)=b1] [ClOrdID(11)=a2 | CxlQty(84)=c2] \
+         | 50000=custom"
+    );
+
+    Ok(())
+}
@@ -287,3 +287,122 @@ fn test_modify_group() {
          10=127\u{1}"
     );
 }
+
+fn read_texts(msg: &Message) -> Vec<String> {
+    (1..=msg.group_count(MSG_NO_LINES_OF_TEXT) as i32)
+        .map(|index| {
+            msg.clone_group(index, MSG_NO_LINES_OF_TEXT)
+                .and_then(|g| g.get_field(MSG_TEXT))
+                .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_remove_group() {
+    let mut msg = build_news("Great news", &["line 1", "line 2", "line 3"]).unwrap();
+    assert_eq!(msg.group_count(MSG_NO_LINES_OF_TEXT), 3);
+
+    msg.remove_group(2, MSG_NO_LINES_OF_TEXT).unwrap();
+    assert_eq!(msg.group_count(MSG_NO_LINES_OF_TEXT), 2);
+    assert_eq!(msg.get_field(MSG_NO_LINES_OF_TEXT).as_deref(), Some("2"));
+    assert_eq!(read_texts(&msg), vec!["line 1", "line 3"]);
+
+    // Invalid index or tag.
+    for (index, tag) in [
+        (0, MSG_NO_LINES_OF_TEXT),
+        (3, MSG_NO_LINES_OF_TEXT),
+        (1, 99),
+    ] {
+        assert!(matches!(
+            msg.remove_group(index, tag),
+            Err(QuickFixError::FieldNotFound(_))
+        ));
+    }
+    assert_eq!(msg.group_count(MSG_NO_LINES_OF_TEXT), 2);
+
+    // Removing last group also remove counter.
+    msg.remove_group(1, MSG_NO_LINES_OF_TEXT).unwrap();
+    msg.remove_group(1, MSG_NO_LINES_OF_TEXT).unwrap();
+    assert_eq!(msg.group_count(MSG_NO_LINES_OF_TEXT), 0);
+    assert_eq!(msg.get_field(MSG_NO_LINES_OF_TEXT), None);
+}
+
+#[test]
+fn test_replace_group() {
+    let mut msg = build_news("Great news", &["line 1", "line 2"]).unwrap();
+
+    let mut group = Group::try_new(MSG_NO_LINES_OF_TEXT, MSG_TEXT).unwrap();
+    group.set_field(MSG_TEXT, "new line").unwrap();
+
+    msg.replace_group(2, &group).unwrap();
+    assert_eq!(read_texts(&msg), vec!["line 1", "new line"]);
+    assert_eq!(msg.get_field(MSG_NO_LINES_OF_TEXT).as_deref(), Some("2"));
+
+    // Invalid index.
+    assert!(matches!(
+        msg.replace_group(3, &group),
+        Err(QuickFixError::FieldNotFound(_))
+    ));
+    assert!(matches!(
+        msg.replace_group(0, &group),
+        Err(QuickFixError::FieldNotFound(_))
+    ));
+
+    // Invalid tag.
+    let other = Group::try_new(99, 100).unwrap();
+    assert!(matches!(
+        msg.replace_group(1, &other),
+        Err(QuickFixError::FieldNotFound(_))
+    ));
+    assert_eq!(read_texts(&msg), vec!["line 1", "new line"]);
+}
+
+#[test]
+fn test_remove_all_groups() {
+    let mut msg = build_news("Great news", &["line 1", "line 2"]).unwrap();
+
+    msg.remove_all_groups(MSG_NO_LINES_OF_TEXT).unwrap();
+    assert_eq!(msg.group_count(MSG_NO_LINES_OF_TEXT), 0);
+    assert_eq!(msg.get_field(MSG_NO_LINES_OF_TEXT), None);
+    assert_eq!(msg.get_field(MSG_HEADLINE).as_deref(), Some("Great news"));
+
+    // Removing missing groups is not an error.
+    msg.remove_all_groups(MSG_NO_LINES_OF_TEXT).unwrap();
+}
+
+#[test]
+fn test_group_operations_on_other_field_maps() -> Result<(), QuickFixError> {
+    fn check<T: FieldMap>(obj: &mut T) -> Result<(), QuickFixError> {
+        for value in ["a", "b", "c"] {
+            let mut group = Group::try_new(100, 101)?;
+            group.set_field(101, value)?;
+            obj.add_group(&group)?;
+        }
+        assert_eq!(obj.group_count(100), 3);
+        assert_eq!(obj.group_count(200), 0);
+
+        obj.remove_group(1, 100)?;
+        let mut group = Group::try_new(100, 101)?;
+        group.set_field(101, "z")?;
+        obj.replace_group(2, &group)?;
+        assert_eq!(obj.get_field(100).as_deref(), Some("2"));
+        assert_eq!(
+            obj.clone_group(1, 100).unwrap().get_field(101).as_deref(),
+            Some("b")
+        );
+        assert_eq!(
+            obj.clone_group(2, 100).unwrap().get_field(101).as_deref(),
+            Some("z")
+        );
+
+        obj.remove_all_groups(100)?;
+        assert_eq!(obj.group_count(100), 0);
+        Ok(())
+    }
+
+    check(&mut Header::new())?;
+    check(&mut Trailer::new())?;
+    check(&mut Group::try_new(42, 43)?)?;
+    Ok(())
+}
@@ -189,3 +189,172 @@ fn test_copy_trailer() {
     );
     assert_eq!(cpy.get_field(5000).as_deref(), Some("hello"));
 }
+
+#[test]
+fn test_data_field_round_trip() -> Result<(), QuickFixError> {
+    const RAW_DATA_LENGTH: i32 = 95;
+    const RAW_DATA: i32 = 96;
+    const XML_DATA_LEN: i32 = 212;
+    const XML_DATA: i32 = 213;
+
+    let raw_data = b"a\x01b=c\x01";
+    let xml_data = b"<x>\x01</x>";
+
+    let mut msg = Message::new();
+    msg.set_field(RAW_DATA_LENGTH, raw_data.len())?;
+    msg.set_field_bytes(RAW_DATA, raw_data)?;
+    msg.with_header_mut(|x| {
+        x.set_field(XML_DATA_LEN, xml_data.len())?;
+        x.set_field_bytes(XML_DATA, xml_data)
+    })?;
+
+    let text = msg.to_fix_string()?;
+    assert_eq!(
+        text,
+        "9=34\u{1}212=8\u{1}213=<x>\u{1}</x>\u{1}95=6\u{1}96=a\u{1}b=c\u{1}\u{1}10=197\u{1}"
+    );
+
+    // Parse message back from text and from bytes.
+    for parsed in [
+        Message::try_from_text(&text)?,
+        Message::try_from_bytes(text.as_bytes())?,
+    ] {
+        assert_eq!(
+            parsed.get_field_bytes(RAW_DATA).as_deref(),
+            Some(&raw_data[..])
+        );
+        assert_eq!(
+            parsed
+                .with_header(|x| x.get_field_bytes(XML_DATA))
+                .as_deref(),
+            Some(&xml_data[..])
+        );
+        assert_eq!(parsed.to_fix_string()?, text);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_data_field_with_nul_round_trip() -> Result<(), QuickFixError> {
+    const ENCODED_TEXT_LEN: i32 = 354;
+    const ENCODED_TEXT: i32 = 355;
+
+    let value = b"\x00\x01\xff\x00";
+
+    let mut msg = Message::new();
+    msg.set_field(ENCODED_TEXT_LEN, value.len())?;
+    msg.set_field_bytes(ENCODED_TEXT, value)?;
+
+    // Output buffer contains data followed by a final NUL byte.
+    let mut bytes = msg.to_fix_raw_bytes()?;
+    assert_eq!(bytes.pop(), Some(0));
+    assert!(bytes.windows(value.len()).any(|x| x == value));
+
+    // Parse message back from bytes.
+    let parsed = Message::try_from_bytes(&bytes)?;
+    assert_eq!(
+        parsed.get_field_bytes(ENCODED_TEXT).as_deref(),
+        Some(&value[..])
+    );
+    assert_eq!(parsed.get_field(ENCODED_TEXT_LEN).as_deref(), Some("4"));
+
+    let mut parsed_bytes = parsed.to_fix_raw_bytes()?;
+    parsed_bytes.pop();
+    assert_eq!(parsed_bytes, bytes);
+
+    Ok(())
+}
+
+#[test]
+fn test_write_fix_bytes() -> Result<(), QuickFixError> {
+    let mut msg = Message::try_from_text("42=foo\u{1}56=bar\u{1}")?;
+    let mut buffer = Vec::new();
+
+    // Check same output as other serialization methods, without trailing NUL.
+    msg.write_fix_bytes(&mut buffer)?;
+    assert_eq!(buffer, b"9=14\x0156=bar\x0142=foo\x0110=162\x01");
+    let mut raw_bytes = msg.to_fix_raw_bytes()?;
+    assert_eq!(raw_bytes.pop(), Some(0));
+    assert_eq!(buffer, raw_bytes);
+
+    // Check buffer is appended to.
+    msg.set_field(42, "x")?;
+    msg.write_fix_bytes(&mut buffer)?;
+    assert_eq!(
+        buffer,
+        b"9=14\x0156=bar\x0142=foo\x0110=162\x019=12\x0156=bar\x0142=x\x0110=212\x01"
+    );
+
+    // Check buffer allocation can be reused.
+    buffer.clear();
+    let capacity = buffer.capacity();
+    msg.write_fix_bytes(&mut buffer)?;
+    assert_eq!(buffer, b"9=12\x0156=bar\x0142=x\x0110=212\x01");
+    assert_eq!(buffer.capacity(), capacity);
+
+    // Check header and trailer.
+    buffer.clear();
+    msg.with_header(|x| x.write_fix_bytes(&mut buffer))?;
+    assert_eq!(buffer, b"9=12\x0156=bar\x01");
+
+    buffer.clear();
+    msg.with_trailer(|x| x.write_fix_bytes(&mut buffer))?;
+    assert_eq!(buffer, b"10=212\x01");
+
+    buffer.clear();
+    Header::new().write_fix_bytes(&mut buffer)?;
+    Trailer::new().write_fix_bytes(&mut buffer)?;
+    assert!(buffer.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_msg_type() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    assert_eq!(msg.msg_type(), None);
+    assert!(!msg.is_admin());
+    assert!(!msg.is_app());
+
+    msg.with_header_mut(|h| h.set_field(35, "A"))?;
+    assert_eq!(msg.msg_type().as_deref(), Some("A"));
+    assert!(msg.is_admin());
+    assert!(!msg.is_app());
+
+    msg.with_header_mut(|h| h.set_field(35, "D"))?;
+    assert_eq!(msg.msg_type().as_deref(), Some("D"));
+    assert!(!msg.is_admin());
+    assert!(msg.is_app());
+
+    Ok(())
+}
+
+#[test]
+fn test_new_reply_to() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| -> Result<(), QuickFixError> {
+        h.set_field(8, "FIX.4.4")?;
+        h.set_field(35, "D")?;
+        h.set_field(49, "CLIENT")?;
+        h.set_field(116, "ON_BEHALF_SUB")?;
+        h.set_field(56, "SERVER")?;
+        h.set_field(115, "ON_BEHALF")?;
+        h.set_field(128, "DELIVER_TO")?;
+        Ok(())
+    })?;
+
+    let reply = Message::new_reply_to(&msg)?;
+    reply.with_header(|h| {
+        assert_eq!(h.get_field(8).as_deref(), Some("FIX.4.4"));
+        assert_eq!(h.get_field(35), None);
+        assert_eq!(h.get_field(49).as_deref(), Some("SERVER"));
+        assert_eq!(h.get_field(56).as_deref(), Some("CLIENT"));
+        assert_eq!(h.get_field(116), None);
+        assert_eq!(h.get_field(129).as_deref(), Some("ON_BEHALF_SUB"));
+        assert_eq!(h.get_field(115).as_deref(), Some("DELIVER_TO"));
+        assert_eq!(h.get_field(128).as_deref(), Some("ON_BEHALF"));
+    });
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+struct BufferedApp {
+    buffer: OutboundBuffer,
+    sent: Mutex<Vec<String>>,
+    expired: Arc<Mutex<Vec<String>>>,
+}
+
+impl BufferedApp {
+    fn new() -> Self {
+        let expired = Arc::new(Mutex::new(Vec::new()));
+        let expired_headlines = Arc::clone(&expired);
+        let buffer = OutboundBuffer::new().with_on_expired(move |msg, session_id| {
+            assert_eq!(
+                session_id.to_repr(),
+                ServerType::Sender.session_id().to_repr()
+            );
+            expired_headlines
+                .lock()
+                .unwrap()
+                .push(msg.get_field(MSG_HEADLINE).unwrap());
+        });
+
+        Self {
+            buffer,
+            sent: Mutex::default(),
+            expired,
+        }
+    }
+
+    fn sent(&self) -> Vec<String> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    fn expired(&self) -> Vec<String> {
+        self.expired.lock().unwrap().clone()
+    }
+}
+
+impl ApplicationCallback for BufferedApp {
+    fn on_logon(&self, session: &SessionId) {
+        self.buffer.on_logon(session).unwrap();
+    }
+
+    fn on_logout(&self, session: &SessionId) {
+        self.buffer.on_logout(session);
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, _session: &SessionId) -> Result<(), MsgToAppError> {
+        self.sent
+            .lock()
+            .unwrap()
+            .push(msg.get_field(MSG_HEADLINE).unwrap());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_store_and_forward() -> Result<(), QuickFixError> {
+    let sender = BufferedApp::new();
+    let session_id = ServerType::Sender.session_id();
+
+    let communication_port = find_available_port();
+    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
+    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
+
+    let log_factory = LogFactory::try_new(&NullLogger)?;
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+
+    // Messages are held while session is not logged on.
+    let buffer = &sender.buffer;
+    let outcomes = [
+        buffer.send(build_news("KEEP-1", &[])?, &session_id)?,
+        buffer.send_with_expiry(
+            build_news("EXPIRE-1", &[])?,
+            &session_id,
+            Duration::from_millis(10),
+        )?,
+        buffer.send_with_expiry(
+            build_news("KEEP-2", &[])?,
+            &session_id,
+            Duration::from_secs(60),
+        )?,
+    ];
+    assert_eq!(outcomes, [BufferedSend::Buffered; 3]);
+    assert_eq!(buffer.buffered_count(&session_id), 3);
+    assert!(!buffer.is_logged_on(&session_id));
+
+    // Expired messages can be dropped before logon.
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(buffer.purge_expired(), 1);
+    assert_eq!(buffer.purge_expired(), 0);
+    assert_eq!(sender.expired(), ["EXPIRE-1"]);
+    assert_eq!(buffer.buffered_count(&session_id), 2);
+
+    // ... or when they are about to be sent.
+    assert_eq!(
+        buffer.send_with_expiry(
+            build_news("EXPIRE-2", &[])?,
+            &session_id,
+            Duration::from_millis(10),
+        )?,
+        BufferedSend::Buffered
+    );
+    thread::sleep(Duration::from_millis(50));
+
+    // Buffered messages are flushed in order after logon.
+    socket_receiver.start()?;
+    socket_sender.start()?;
+    wait_until(|| socket_sender.is_logged_on().unwrap() && socket_receiver.is_logged_on().unwrap());
+    wait_until(|| buffer.is_logged_on(&session_id));
+    assert_eq!(sender.sent(), ["KEEP-1", "KEEP-2"]);
+    assert_eq!(sender.expired(), ["EXPIRE-1", "EXPIRE-2"]);
+    assert_eq!(buffer.buffered_count(&session_id), 0);
+
+    // Logged on session sends messages straight away.
+    assert_eq!(
+        buffer.send(build_news("LIVE", &[])?, &session_id)?,
+        BufferedSend::Sent
+    );
+    assert_eq!(sender.sent(), ["KEEP-1", "KEEP-2", "LIVE"]);
+
+    // Buffer again once logged out.
+    socket_sender.stop()?;
+    assert!(!buffer.is_logged_on(&session_id));
+    assert_eq!(
+        buffer.send(build_news("LATE", &[])?, &session_id)?,
+        BufferedSend::Buffered
+    );
+    assert_eq!(buffer.buffered_count(&session_id), 1);
+
+    socket_receiver.stop()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_failed_flush_keeps_message() -> Result<(), QuickFixError> {
+    let buffer = OutboundBuffer::new();
+    let unknown = SessionId::try_new("FIX.4.4", "FOO", "BAR", "")?;
+
+    for headline in ["FIRST", "SECOND"] {
+        assert_eq!(
+            buffer.send(build_news(headline, &[])?, &unknown)?,
+            BufferedSend::Buffered
+        );
+    }
+
+    // Message that cannot be sent is kept, and next ones wait behind it.
+    assert!(matches!(
+        buffer.on_logon(&unknown),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+    assert_eq!(buffer.buffered_count(&unknown), 2);
+    assert!(!buffer.is_logged_on(&unknown));
+    assert_eq!(
+        buffer.send(build_news("THIRD", &[])?, &unknown)?,
+        BufferedSend::Buffered
+    );
+    assert_eq!(buffer.buffered_count(&unknown), 3);
+
+    // Flush can be retried while session is logged on.
+    assert!(matches!(
+        buffer.retry_flush(&unknown),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+    assert_eq!(buffer.buffered_count(&unknown), 3);
+
+    // Nothing is retried once session is logged out.
+    buffer.on_logout(&unknown);
+    assert_eq!(buffer.retry_flush(&unknown)?, 0);
+    assert_eq!(buffer.buffered_count(&unknown), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_failed_send_keeps_message() -> Result<(), QuickFixError> {
+    let buffer = OutboundBuffer::new();
+    let unknown = SessionId::try_new("FIX.4.4", "FOO", "BAR", "")?;
+
+    // Nothing to flush, so session is considered logged on.
+    assert_eq!(buffer.on_logon(&unknown)?, 0);
+    assert!(buffer.is_logged_on(&unknown));
+
+    // Message that cannot be sent is buffered, and next ones wait for flush to be retried.
+    assert!(matches!(
+        buffer.send(build_news("FIRST", &[])?, &unknown),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+    assert_eq!(buffer.buffered_count(&unknown), 1);
+    assert!(!buffer.is_logged_on(&unknown));
+    assert!(matches!(
+        buffer.retry_flush(&unknown),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+    assert_eq!(buffer.buffered_count(&unknown), 1);
+
+    Ok(())
+}
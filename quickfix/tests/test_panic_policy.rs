@@ -0,0 +1,163 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::Receiver,
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+/// Application panicking on received news.
+struct PanickingApp;
+
+impl ApplicationCallback for PanickingApp {
+    fn on_msg_from_app(&self, msg: &Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        panic!("Cannot handle {}", msg.get_field(MSG_HEADLINE).unwrap());
+    }
+}
+
+/// Logger panicking on every event.
+struct PanickingLogger;
+
+impl LogCallback for PanickingLogger {
+    fn on_event(&self, _session_id: Option<&SessionId>, _msg: &str) {
+        panic!("Logger is broken");
+    }
+}
+
+/// Panics caught by hook, as callback name, session and message.
+type PanicRecords = Arc<Mutex<Vec<(String, Option<String>, Option<String>)>>>;
+
+fn record_panics(records: &PanicRecords) -> impl Fn(&CallbackPanic<'_>) + Send + Sync + 'static {
+    let records = Arc::clone(records);
+    move |panic| {
+        records.lock().unwrap().push((
+            panic.callback.to_string(),
+            panic.session_id.map(SessionId::to_repr),
+            panic.message().map(str::to_string),
+        ));
+    }
+}
+
+/// Wait for an event matching given message type.
+fn wait_msg_type(events: &Receiver<FixEvent>, expected: &str) {
+    let start = Instant::now();
+    loop {
+        let timeout = Duration::from_secs(10).saturating_sub(start.elapsed());
+        let msg_type = match events.recv_timeout(timeout).expect("Timeout") {
+            FixEvent::FromAdmin(msg, _) | FixEvent::FromApp(msg, _, _) => msg.msg_type(),
+            FixEvent::Logout(_) => Some("Logout".to_string()),
+            _ => None,
+        };
+        if msg_type.as_deref() == Some(expected) {
+            return;
+        }
+    }
+}
+
+/// Send news to a panicking receiver, and wait for sender to receive the expected message.
+fn send_to_panicking_receiver(
+    policy: PanicPolicy,
+    expected_msg_type: &str,
+) -> Result<PanicRecords, QuickFixError> {
+    let records = PanicRecords::default();
+    let (sender, events) = ChannelApplication::new(64);
+
+    let communication_port = find_available_port();
+    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
+    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
+
+    let log_factory = LogFactory::try_new(&NullLogger)?;
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&PanickingApp)?
+        .with_panic_policy(policy)
+        .with_panic_hook(record_panics(&records));
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+
+    socket_receiver.start()?;
+    socket_sender.start()?;
+    wait_msg_type(&events, "A");
+
+    send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+    wait_msg_type(&events, expected_msg_type);
+
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+
+    Ok(records)
+}
+
+#[test]
+fn test_panic_reject() -> Result<(), QuickFixError> {
+    // Panic is answered with a business message reject.
+    let records = send_to_panicking_receiver(PanicPolicy::Reject, "j")?;
+    assert_eq!(
+        *records.lock().unwrap(),
+        [(
+            "on_msg_from_app".to_string(),
+            Some(ServerType::Receiver.session_id().to_repr()),
+            Some("Cannot handle Hello".to_string())
+        )]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_panic_disconnect() -> Result<(), QuickFixError> {
+    // Panic logs out the session.
+    let records = send_to_panicking_receiver(PanicPolicy::Disconnect, "Logout")?;
+    assert_eq!(records.lock().unwrap().len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_logger_panic() -> Result<(), QuickFixError> {
+    let panic_count = Arc::new(AtomicUsize::new(0));
+    let hook_panic_count = Arc::clone(&panic_count);
+
+    let log_factory = LogFactory::try_new(&PanickingLogger)?
+        .with_panic_policy(PanicPolicy::Log)
+        .with_panic_hook(move |panic| {
+            assert_eq!(panic.callback, "on_event");
+            assert_eq!(panic.message(), Some("Logger is broken"));
+            hook_panic_count.fetch_add(1, Ordering::SeqCst);
+        });
+
+    // Session creation logs some events.
+    let settings = build_settings(ServerType::Receiver, find_available_port())?;
+    let app = Application::try_new(&NullFixApplication)?;
+    let message_store_factory = MemoryMessageStoreFactory::new();
+    let mut acceptor = Acceptor::try_new(
+        &settings,
+        &app,
+        &message_store_factory,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    acceptor.stop()?;
+
+    assert!(panic_count.load(Ordering::SeqCst) > 0);
+    Ok(())
+}
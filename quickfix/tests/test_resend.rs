@@ -0,0 +1,114 @@
+use std::sync::Mutex;
+
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+/// Gap fill stale news and tag the others before resending them.
+#[derive(Default)]
+struct ResendFilter {
+    resend_contexts: Mutex<Vec<(String, ResendContext)>>,
+    resent_headlines: Mutex<Vec<String>>,
+}
+
+impl ApplicationCallback for ResendFilter {
+    fn on_resend(
+        &self,
+        msg: &mut Message,
+        context: &ResendContext,
+        _session: &SessionId,
+    ) -> ResendAction {
+        let headline = msg.get_field(MSG_HEADLINE).unwrap();
+        self.resend_contexts
+            .lock()
+            .unwrap()
+            .push((headline.clone(), *context));
+
+        if headline.starts_with("STALE") {
+            ResendAction::GapFill
+        } else {
+            msg.set_field(MSG_HEADLINE, format!("{headline} (resent)"))
+                .unwrap();
+            ResendAction::Resend
+        }
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, _session: &SessionId) -> Result<(), MsgToAppError> {
+        let is_poss_dup = msg.with_header(|header| header.get_field(43).as_deref() == Some("Y"));
+        if is_poss_dup {
+            self.resent_headlines
+                .lock()
+                .unwrap()
+                .push(msg.get_field(MSG_HEADLINE).unwrap());
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_resend_policy() -> Result<(), QuickFixError> {
+    let sender = ResendFilter::default();
+
+    let communication_port = find_available_port();
+    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
+    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
+
+    let log_factory = LogFactory::try_new(&StdLogger::Stdout)?;
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+
+    socket_receiver.start()?;
+    socket_sender.start()?;
+    wait_until(|| socket_sender.is_logged_on().unwrap() && socket_receiver.is_logged_on().unwrap());
+
+    let mut session_receiver = socket_receiver.session(ServerType::Receiver.session_id())?;
+    for headline in ["STALE-1", "FRESH", "STALE-2"] {
+        send_to_target(build_news(headline, &[])?, &ServerType::Sender.session_id())?;
+    }
+    wait_until(|| session_receiver.next_target_msg_seq_num().unwrap() == 5);
+
+    // Nothing is resent until counterparty asks for it.
+    assert!(sender.resend_contexts.lock().unwrap().is_empty());
+    assert!(sender.resent_headlines.lock().unwrap().is_empty());
+
+    assert!(session_receiver.send_resend_request(1, 0)?);
+    wait_until(|| sender.resend_contexts.lock().unwrap().len() == 3);
+    wait_until(|| !sender.resent_headlines.lock().unwrap().is_empty());
+
+    // Every stored application message goes through the policy, with its resend context.
+    let resend_contexts = sender.resend_contexts.lock().unwrap().clone();
+    let headlines: Vec<_> = resend_contexts.iter().map(|(x, _)| x.as_str()).collect();
+    assert_eq!(headlines, ["STALE-1", "FRESH", "STALE-2"]);
+    for (_, context) in &resend_contexts {
+        assert!(context.poss_dup_flag);
+        assert!(context.orig_sending_time.is_some());
+    }
+
+    // Only fresh message is resent, with its updates.
+    assert_eq!(*sender.resent_headlines.lock().unwrap(), ["FRESH (resent)"]);
+    assert!(socket_sender.is_logged_on()?);
+    assert!(socket_receiver.is_logged_on()?);
+
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "serde")]
+
+use quickfix::*;
+use serde::de::DeserializeSeed;
+use serde_json::json;
+
+use crate::utils::*;
+
+mod utils;
+
+const FIX44_PATH: &str = "../quickfix-ffi/libquickfix/spec/FIX44.xml";
+
+fn build_message() -> Result<Message, QuickFixError> {
+    let mut msg = build_list_status(
+        "foo",
+        &[&[(11, "a1"), (14, "b1")], &[(11, "a2"), (84, "c2")]],
+    )?;
+    msg.with_header_mut(|h| -> Result<(), QuickFixError> {
+        h.set_field(MSG_TYPE, "N")?;
+        h.set_field(49, "SENDER")
+    })?;
+    msg.with_trailer_mut(|t| t.set_field(93, 4))?;
+    Ok(msg)
+}
+
+#[test]
+fn test_serialize_message() -> Result<(), QuickFixError> {
+    let msg = build_message()?;
+
+    assert_eq!(
+        serde_json::to_value(&msg).unwrap(),
+        json!({
+            "header": { "35": "N", "49": "SENDER" },
+            "body": {
+                "66": "foo",
+                "73": [
+                    { "11": "a1", "14": "b1" },
+                    { "11": "a2", "84": "c2" },
+                ],
+            },
+            "trailer": { "93": "4" },
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_serialize_with_field_names() -> Result<(), QuickFixError> {
+    let dictionary = DataDictionary::try_from_path(FIX44_PATH)?;
+    let mut msg = build_message()?;
+    msg.set_field(50000, "custom")?;
+
+    assert_eq!(
+        serde_json::to_value(WithFieldNames::new(&msg, &dictionary)).unwrap(),
+        json!({
+            "header": { "MsgType": "N", "SenderCompID": "SENDER" },
+            "body": {
+                "ListID": "foo",
+                "NoOrders": [
+                    { "ClOrdID": "a1", "CumQty": "b1" },
+                    { "ClOrdID": "a2", "CxlQty": "c2" },
+                ],
+                "50000": "custom",
+            },
+            "trailer": { "SignatureLength": "4" },
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_message_round_trip() -> Result<(), QuickFixError> {
+    let msg = build_message()?;
+
+    let text = serde_json::to_string(&msg).unwrap();
+    let decoded: Message = serde_json::from_str(&text).unwrap();
+
+    assert_eq!(decoded.to_fix_string()?, msg.to_fix_string()?);
+    assert_eq!(
+        decoded.clone_group(2, 73).unwrap().get_field(84).as_deref(),
+        Some("c2")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_deserialize_with_field_names() -> Result<(), QuickFixError> {
+    let dictionary = DataDictionary::try_from_path(FIX44_PATH)?;
+    let text = r#"{
+        "header": { "MsgType": "B" },
+        "body": { "Headline": "Hello", "NoLinesOfText": [{ "Text": "line" }], "50000": 42 }
+    }"#;
+
+    let msg = FieldNamesSeed::<Message>::new(&dictionary)
+        .deserialize(&mut serde_json::Deserializer::from_str(text))
+        .unwrap();
+
+    assert_eq!(msg.msg_type().as_deref(), Some("B"));
+    assert_eq!(msg.get_field(MSG_HEADLINE).as_deref(), Some("Hello"));
+    assert_eq!(msg.get_field(MSG_NO_LINES_OF_TEXT).as_deref(), Some("1"));
+    assert_eq!(msg.get_field(50000).as_deref(), Some("42"));
+    assert_eq!(
+        msg.clone_group(1, MSG_NO_LINES_OF_TEXT)
+            .unwrap()
+            .get_field(MSG_TEXT)
+            .as_deref(),
+        Some("line")
+    );
+
+    // Field names are only accepted with a dictionary.
+    let err = serde_json::from_str::<Message>(text).unwrap_err();
+    assert!(err.to_string().contains("unknown FIX field: MsgType"));
+
+    let err = FieldNamesSeed::<Message>::new(&dictionary)
+        .deserialize(&mut serde_json::Deserializer::from_str(
+            r#"{"body": {"NotAField": "1"}}"#,
+        ))
+        .unwrap_err();
+    assert!(err.to_string().contains("unknown FIX field: NotAField"));
+
+    Ok(())
+}
+
+#[test]
+fn test_header_trailer_round_trip() -> Result<(), QuickFixError> {
+    let msg = build_message()?;
+
+    let header = msg.clone_header();
+    let text = serde_json::to_string(&header).unwrap();
+    assert_eq!(text, r#"{"35":"N","49":"SENDER"}"#);
+    let decoded: Header = serde_json::from_str(&text).unwrap();
+    assert_eq!(decoded.get_field(49).as_deref(), Some("SENDER"));
+
+    let trailer = msg.clone_trailer();
+    let text = serde_json::to_string(&trailer).unwrap();
+    assert_eq!(text, r#"{"93":"4"}"#);
+    let decoded: Trailer = serde_json::from_str(&text).unwrap();
+    assert_eq!(decoded.get_field(93).as_deref(), Some("4"));
+
+    Ok(())
+}
+
+#[test]
+fn test_group_round_trip() -> Result<(), QuickFixError> {
+    let mut group = Group::try_with_orders(100, 101, &[101, 102])?;
+    group.set_field(101, "v1")?;
+    group.add_group(&{
+        let mut sub = Group::try_with_orders(200, 201, &[201, 202])?;
+        sub.set_field(201, "x1")?;
+        sub
+    })?;
+
+    let value = serde_json::to_value(&group).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "field_id": 100,
+            "delim": 101,
+            "fields": { "101": "v1", "200": [{ "201": "x1" }] },
+        })
+    );
+
+    let decoded: Group = serde_json::from_value(value).unwrap();
+    assert_eq!(decoded.field_id(), 100);
+    assert_eq!(decoded.delim(), 101);
+    assert_eq!(
+        decoded.iter_fields().collect::<Vec<_>>(),
+        group.iter_fields().collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
@@ -47,6 +47,19 @@ fn test_session_login_logout() -> Result<(), QuickFixError> {
     assert_eq!(sender.session_created(), 1);
     assert_eq!(receiver.session_created(), 1);
 
+    // Sessions can be discovered from their container.
+    let session_ids = socket_sender.session_ids()?;
+    assert_eq!(session_ids.len(), 1);
+    assert_eq!(
+        session_ids[0].to_repr(),
+        ServerType::Sender.session_id().to_repr()
+    );
+    assert_eq!(
+        socket_receiver.session_ids()?[0].to_repr(),
+        ServerType::Receiver.session_id().to_repr()
+    );
+    assert_eq!(socket_receiver.sessions()?.len(), 1);
+
     // Start the app
     socket_receiver.start()?;
     socket_sender.start()?;
@@ -73,6 +86,49 @@ fn test_session_login_logout() -> Result<(), QuickFixError> {
     // Play with session state
     assert!(session.is_logged_on().unwrap());
 
+    let status = session.status().unwrap();
+    assert_eq!(
+        status.session_id.to_repr(),
+        ServerType::Sender.session_id().to_repr()
+    );
+    assert!(status.is_enabled);
+    assert!(status.is_session_time);
+    assert!(status.is_logged_on());
+    assert!(status.is_initiator);
+    assert!(!status.is_acceptor());
+    assert_eq!(status.heartbeat_interval, Some(20));
+    assert_eq!(session.heartbeat_interval().unwrap(), Some(20));
+
+    // Acceptor uses the heartbeat interval from received logon.
+    let status = socket_receiver
+        .session_status(ServerType::Receiver.session_id())
+        .unwrap();
+    assert_eq!(
+        status.session_id.to_repr(),
+        ServerType::Receiver.session_id().to_repr()
+    );
+    assert!(status.is_logged_on());
+    assert!(status.is_acceptor());
+    assert_eq!(status.heartbeat_interval, Some(20));
+    assert!(status.creation_time.date.year >= 2024);
+
+    // Play with sequence numbers: logon + news sent, logon received.
+    assert_eq!(session.next_sender_msg_seq_num().unwrap(), 3);
+    assert_eq!(session.next_target_msg_seq_num().unwrap(), 2);
+
+    session.set_next_sender_msg_seq_num(42).unwrap();
+    session.set_next_target_msg_seq_num(24).unwrap();
+    assert_eq!(session.next_sender_msg_seq_num().unwrap(), 42);
+    assert_eq!(session.next_target_msg_seq_num().unwrap(), 24);
+
+    session.set_next_sender_msg_seq_num(3).unwrap();
+    session.set_next_target_msg_seq_num(2).unwrap();
+
+    // Memory store keeps its state on refresh.
+    session.refresh().unwrap();
+    assert_eq!(session.next_sender_msg_seq_num().unwrap(), 3);
+    assert_eq!(session.next_target_msg_seq_num().unwrap(), 2);
+
     // Logout
     session.logout().unwrap();
     while session.is_logged_on().unwrap() {
@@ -0,0 +1,130 @@
+use std::{sync::Mutex, time::Duration};
+
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+#[derive(Default)]
+struct AdminRecorder {
+    heartbeats: HeartbeatTracker,
+    received_msg_types: Mutex<Vec<String>>,
+}
+
+impl AdminRecorder {
+    fn has_received(&self, msg_type: &str) -> bool {
+        self.received_msg_types
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|x| x == msg_type)
+    }
+}
+
+impl ApplicationCallback for AdminRecorder {
+    fn on_msg_from_admin(
+        &self,
+        msg: &Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.heartbeats.on_msg_from_admin(msg, session);
+        if let Some(msg_type) = msg.msg_type() {
+            self.received_msg_types.lock().unwrap().push(msg_type);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_session_admin_messages() -> Result<(), QuickFixError> {
+    let sender = AdminRecorder::default();
+    let receiver = AdminRecorder::default();
+
+    let communication_port = find_available_port();
+    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
+    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
+
+    let log_factory = LogFactory::try_new(&StdLogger::Stdout)?;
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&receiver)?;
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+
+    socket_receiver.start()?;
+    socket_sender.start()?;
+    wait_until(|| socket_sender.is_logged_on().unwrap() && socket_receiver.is_logged_on().unwrap());
+
+    let sender_id = ServerType::Sender.session_id();
+    let mut session_sender = socket_sender.session(sender_id.clone())?;
+    let mut session_receiver = socket_receiver.session(ServerType::Receiver.session_id())?;
+
+    // Test request is answered with a matching heartbeat.
+    assert!(sender
+        .heartbeats
+        .send_test_request(&mut session_sender, "PING-1")?);
+    let latency = sender
+        .heartbeats
+        .wait_heartbeat(&sender_id, "PING-1", Duration::from_secs(10))
+        .expect("No heartbeat received");
+    assert!(latency < Duration::from_secs(10));
+    assert_eq!(sender.heartbeats.latency(&sender_id, "PING-1"), None);
+    assert_eq!(
+        sender
+            .heartbeats
+            .wait_heartbeat(&sender_id, "PING-2", Duration::from_millis(50)),
+        None
+    );
+
+    // Gap fill skips some sequence numbers.
+    let next_seq_num = session_sender.next_sender_msg_seq_num()?;
+    assert!(session_sender.send_sequence_reset(next_seq_num + 5, true)?);
+    assert_eq!(session_sender.next_sender_msg_seq_num()?, next_seq_num + 5);
+    wait_until(|| session_receiver.next_target_msg_seq_num().unwrap() == next_seq_num + 5);
+
+    // Reset jumps to a new sequence number.
+    assert!(session_sender.send_sequence_reset(next_seq_num + 20, false)?);
+    assert_eq!(session_sender.next_sender_msg_seq_num()?, next_seq_num + 20);
+    wait_until(|| session_receiver.next_target_msg_seq_num().unwrap() == next_seq_num + 20);
+
+    // Counterparty resends (admin) messages as gap fill, that are already received.
+    assert!(session_sender.send_resend_request(1, 0)?);
+    wait_until(|| receiver.has_received("2"));
+    assert!(session_sender.is_logged_on()?);
+
+    // Invalid arguments.
+    for (begin, end) in [(0, 0), (5, 3)] {
+        assert!(matches!(
+            session_sender.send_resend_request(begin, end),
+            Err(QuickFixError::InvalidArgument(_))
+        ));
+    }
+    let next_seq_num = session_sender.next_sender_msg_seq_num()?;
+    assert!(matches!(
+        session_sender.send_sequence_reset(next_seq_num, true),
+        Err(QuickFixError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        session_sender.send_test_request("NUL\0"),
+        Err(QuickFixError::InvalidArgument(_))
+    ));
+
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+
+    Ok(())
+}
@@ -0,0 +1,272 @@
+use std::time::SystemTime;
+
+use quickfix::{dictionary_item::*, *};
+
+fn at(value: &str) -> SystemTime {
+    UtcTimestamp::from_fix_value(value)
+        .expect("Invalid timestamp")
+        .into()
+}
+
+#[test]
+fn test_daily() -> Result<(), QuickFixError> {
+    let schedule =
+        SessionSchedule::try_from_items(&[&StartTime("08:00:00"), &EndTime("17:00:00")])?;
+    assert!(!schedule.is_non_stop());
+    assert!(!schedule.use_local_time());
+
+    assert!(!schedule.is_in_session(at("20240105-07:59:59")));
+    assert!(schedule.is_in_session(at("20240105-08:00:00")));
+    assert!(schedule.is_in_session(at("20240105-12:00:00")));
+    assert!(schedule.is_in_session(at("20240105-17:00:00")));
+    assert!(!schedule.is_in_session(at("20240105-17:00:00.001")));
+
+    assert_eq!(
+        schedule.next_start(at("20240105-07:00:00")),
+        Some(at("20240105-08:00:00"))
+    );
+    assert_eq!(
+        schedule.next_start(at("20240105-12:00:00")),
+        Some(at("20240106-08:00:00"))
+    );
+    assert_eq!(
+        schedule.next_end(at("20240105-12:00:00")),
+        Some(at("20240105-17:00:00"))
+    );
+    assert_eq!(
+        schedule.next_end(at("20240105-18:00:00")),
+        Some(at("20240106-17:00:00"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_daily_over_midnight() -> Result<(), QuickFixError> {
+    let schedule =
+        SessionSchedule::try_from_items(&[&StartTime("22:00:00"), &EndTime("06:00:00")])?;
+
+    assert!(schedule.is_in_session(at("20240105-23:00:00")));
+    assert!(schedule.is_in_session(at("20240106-03:00:00")));
+    assert!(!schedule.is_in_session(at("20240105-12:00:00")));
+
+    assert_eq!(
+        schedule.next_end(at("20240105-23:00:00")),
+        Some(at("20240106-06:00:00"))
+    );
+    assert_eq!(
+        schedule.next_start(at("20240106-03:00:00")),
+        Some(at("20240106-22:00:00"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_weekly_over_weekend() -> Result<(), QuickFixError> {
+    // 2024-01-05 is a friday.
+    let schedule = SessionSchedule::try_from_items(&[
+        &StartDay(DayOfWeek::Sunday),
+        &StartTime("18:00:00"),
+        &EndDay(DayOfWeek::Friday),
+        &EndTime("17:00:00"),
+    ])?;
+
+    assert!(schedule.is_in_session(at("20240103-03:00:00")));
+    assert!(schedule.is_in_session(at("20240105-16:00:00")));
+    assert!(!schedule.is_in_session(at("20240105-17:30:00")));
+    assert!(!schedule.is_in_session(at("20240106-12:00:00")));
+    assert!(!schedule.is_in_session(at("20240107-17:00:00")));
+    assert!(schedule.is_in_session(at("20240107-18:00:00")));
+    assert!(schedule.is_in_session(at("20240108-12:00:00")));
+
+    assert_eq!(
+        schedule.next_start(at("20240106-12:00:00")),
+        Some(at("20240107-18:00:00"))
+    );
+    assert_eq!(
+        schedule.next_start(at("20240103-03:00:00")),
+        Some(at("20240107-18:00:00"))
+    );
+    assert_eq!(
+        schedule.next_end(at("20240103-03:00:00")),
+        Some(at("20240105-17:00:00"))
+    );
+    assert_eq!(
+        schedule.next_end(at("20240106-12:00:00")),
+        Some(at("20240112-17:00:00"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_weekly_same_day() -> Result<(), QuickFixError> {
+    // Session only stops on sundays, between 17:00 and 18:00.
+    let schedule = SessionSchedule::try_from_items(&[
+        &("StartDay", "su"),
+        &StartTime("18:00:00"),
+        &("EndDay", "sunday"),
+        &EndTime("17:00:00"),
+    ])?;
+
+    assert!(schedule.is_in_session(at("20240106-17:30:00")));
+    assert!(schedule.is_in_session(at("20240107-16:00:00")));
+    assert!(!schedule.is_in_session(at("20240107-17:30:00")));
+
+    assert_eq!(
+        schedule.next_end(at("20240103-03:00:00")),
+        Some(at("20240107-17:00:00"))
+    );
+    assert_eq!(
+        schedule.next_start(at("20240103-03:00:00")),
+        Some(at("20240107-18:00:00"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_always_in_session() -> Result<(), QuickFixError> {
+    let non_stop = SessionSchedule::try_from_items(&[&NonStopSession(true)])?;
+    let same_time =
+        SessionSchedule::try_from_items(&[&StartTime("00:00:00"), &EndTime("00:00:00")])?;
+    assert!(non_stop.is_non_stop());
+    assert!(!same_time.is_non_stop());
+
+    for schedule in [non_stop, same_time] {
+        for instant in ["20240105-00:00:00", "20240106-12:34:56"] {
+            assert!(schedule.is_in_session(at(instant)));
+            assert!(schedule.is_logon_time(at(instant)));
+        }
+        assert_eq!(schedule.next_start(at("20240105-00:00:00")), None);
+        assert_eq!(schedule.next_end(at("20240105-00:00:00")), None);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_logon_time() -> Result<(), QuickFixError> {
+    let schedule = SessionSchedule::try_from_items(&[
+        &StartTime("08:00:00"),
+        &EndTime("17:00:00"),
+        &LogonTime("09:00:00"),
+        &LogoutTime("16:00:00"),
+    ])?;
+
+    assert!(schedule.is_in_session(at("20240105-08:30:00")));
+    assert!(!schedule.is_logon_time(at("20240105-08:30:00")));
+    assert!(schedule.is_logon_time(at("20240105-12:00:00")));
+    assert!(!schedule.is_logon_time(at("20240105-16:30:00")));
+
+    Ok(())
+}
+
+#[test]
+fn test_local_time() -> Result<(), QuickFixError> {
+    let items: [&dyn DictionaryItem; 3] = [
+        &StartTime("08:00:00"),
+        &EndTime("17:00:00"),
+        &UseLocalTime(true),
+    ];
+
+    // Local time cannot be evaluated without offset.
+    assert!(matches!(
+        SessionSchedule::try_from_items(&items),
+        Err(QuickFixError::ConfigError(_))
+    ));
+
+    let settings = Dictionary::try_from_items(&items)?;
+    let schedule = SessionSchedule::try_from_dictionary_with_local_time(&settings, |_| 3600)?;
+    assert!(schedule.use_local_time());
+    assert!(schedule.is_in_session(at("20240105-07:30:00")));
+    assert!(!schedule.is_in_session(at("20240105-16:30:00")));
+    assert_eq!(
+        schedule.next_start(at("20240105-12:00:00")),
+        Some(at("20240106-07:00:00"))
+    );
+
+    // Offset follows daylight saving time changes.
+    let schedule =
+        SessionSchedule::try_from_dictionary_with_local_time(&settings, central_european_time)?;
+    assert!(schedule.is_in_session(at("20240330-07:30:00")));
+    assert!(!schedule.is_in_session(at("20240401-05:30:00")));
+    assert!(schedule.is_in_session(at("20240401-14:30:00")));
+    assert_eq!(
+        schedule.next_start(at("20240330-12:00:00")),
+        Some(at("20240331-06:00:00"))
+    );
+    assert_eq!(
+        schedule.next_end(at("20240401-12:00:00")),
+        Some(at("20240401-15:00:00"))
+    );
+
+    // Offset is ignored for UTC schedules.
+    let settings = Dictionary::try_from_items(&items[..2])?;
+    let schedule = SessionSchedule::try_from_dictionary_with_local_time(&settings, |_| 3600)?;
+    assert!(!schedule.use_local_time());
+    assert!(!schedule.is_in_session(at("20240105-07:30:00")));
+
+    Ok(())
+}
+
+fn central_european_time(instant: SystemTime) -> i32 {
+    if instant >= at("20240331-01:00:00") {
+        7200
+    } else {
+        3600
+    }
+}
+
+#[test]
+fn test_from_session_settings() -> Result<(), QuickFixError> {
+    let settings = SessionSettings::try_from_path("../configs/settings.ini")?;
+    let session_id = SessionId::try_new("FIX.4.1", "TW", "ARCA1", "")?;
+    let schedule = settings
+        .with_dictionary(Some(&session_id), SessionSchedule::try_from_dictionary)
+        .unwrap()?;
+
+    assert!(schedule.is_in_session(at("20240105-12:30:00")));
+    assert_eq!(
+        schedule.next_end(at("20240105-12:30:00")),
+        Some(at("20240105-23:30:00"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid() {
+    for items in [
+        vec![],
+        vec![&StartTime("08:00:00") as &dyn DictionaryItem],
+        vec![&StartTime("08:00"), &EndTime("17:00:00")],
+        vec![
+            &StartTime("08:00:00"),
+            &EndTime("17:00:00"),
+            &StartDay(DayOfWeek::Monday),
+        ],
+        vec![
+            &StartTime("08:00:00"),
+            &EndTime("17:00:00"),
+            &EndDay(DayOfWeek::Monday),
+        ],
+        vec![&NonStopSession(true), &StartTime("08:00:00")],
+        vec![
+            &StartTime("08:00:00"),
+            &EndTime("17:00:00"),
+            &LogonTime("07:00:00"),
+        ],
+        vec![
+            &StartTime("08:00:00"),
+            &EndTime("17:00:00"),
+            &LogoutTime("18:00:00"),
+        ],
+    ] {
+        assert!(matches!(
+            SessionSchedule::try_from_items(&items),
+            Err(QuickFixError::ConfigError(_))
+        ));
+    }
+}
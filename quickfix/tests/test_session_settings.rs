@@ -63,3 +63,25 @@ fn test_getter_and_setter() {
         None,
     );
 }
+
+#[test]
+fn test_session_ids() {
+    assert!(SessionSettings::new().session_ids().unwrap().is_empty());
+
+    let settings = SessionSettings::try_from_path("../configs/settings.ini").unwrap();
+    let session_ids = settings.session_ids().unwrap();
+    assert_eq!(
+        session_ids.iter().map(|x| x.to_repr()).collect::<Vec<_>>(),
+        vec!["FIX.4.1:TW->ARCA1", "FIX.4.1:TW->ARCA2"]
+    );
+
+    // Session IDs can be used to read back session settings.
+    assert_eq!(
+        settings
+            .with_dictionary(Some(&session_ids[1]), |dict| dict
+                .get::<String>("SocketAcceptPort")
+                .unwrap())
+            .unwrap(),
+        "4001"
+    );
+}
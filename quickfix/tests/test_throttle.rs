@@ -0,0 +1,245 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+#[derive(Default)]
+struct SentRecorder {
+    sent: Mutex<Vec<String>>,
+}
+
+impl SentRecorder {
+    fn sent(&self) -> Vec<String> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+impl ApplicationCallback for SentRecorder {
+    fn on_msg_to_app(&self, msg: &mut Message, _session: &SessionId) -> Result<(), MsgToAppError> {
+        let name = msg
+            .get_field(MSG_HEADLINE)
+            .unwrap_or_else(|| msg.msg_type().unwrap());
+        self.sent.lock().unwrap().push(name);
+        Ok(())
+    }
+}
+
+fn build_cancel() -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| h.set_field(MSG_TYPE, "F"))?;
+    Ok(msg)
+}
+
+#[test]
+fn test_send_priority() -> Result<(), QuickFixError> {
+    assert_eq!(SendPriority::of(&build_cancel()?), SendPriority::High);
+    assert_eq!(
+        SendPriority::of(&build_news("Hello", &[])?),
+        SendPriority::Normal
+    );
+    assert_eq!(SendPriority::of(&Message::new()), SendPriority::Normal);
+    Ok(())
+}
+
+#[test]
+fn test_invalid_throttle() {
+    assert!(matches!(
+        OutboundThrottle::try_new(0, Duration::from_secs(1), ThrottlePolicy::Queue),
+        Err(QuickFixError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        OutboundThrottle::try_new(10, Duration::ZERO, ThrottlePolicy::Queue),
+        Err(QuickFixError::InvalidArgument(_))
+    ));
+
+    let throttle = OutboundThrottle::try_new(10, Duration::from_secs(1), ThrottlePolicy::Queue)
+        .expect("Fail to build throttle");
+    let session_id = ServerType::Sender.session_id();
+    assert!(matches!(
+        throttle.set_limit(&session_id, 0, Duration::from_secs(1)),
+        Err(QuickFixError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        throttle.set_limit(&session_id, 10, Duration::ZERO),
+        Err(QuickFixError::InvalidArgument(_))
+    ));
+}
+
+#[test]
+fn test_throttle_policies() -> Result<(), QuickFixError> {
+    let sender = SentRecorder::default();
+
+    let communication_port = find_available_port();
+    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
+    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
+
+    let log_factory = LogFactory::try_new(&NullLogger)?;
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+
+    socket_receiver.start()?;
+    socket_sender.start()?;
+    wait_until(|| socket_sender.is_logged_on().unwrap() && socket_receiver.is_logged_on().unwrap());
+
+    let session_id = ServerType::Sender.session_id();
+
+    // Queue: messages over the limit wait, cancels first.
+    let throttle = OutboundThrottle::try_new(2, Duration::from_millis(300), ThrottlePolicy::Queue)?;
+    assert_eq!(throttle.policy(), ThrottlePolicy::Queue);
+    assert_eq!(throttle.next_flush_in(), None);
+
+    for headline in ["N1", "N2", "N3", "N4"] {
+        let expect_sent = matches!(headline, "N1" | "N2");
+        let status = throttle.send(build_news(headline, &[])?, &session_id)?;
+        assert_eq!(matches!(status, ThrottledSend::Sent), expect_sent);
+        assert_eq!(matches!(status, ThrottledSend::Queued), !expect_sent);
+    }
+    assert!(matches!(
+        throttle.send(build_cancel()?, &session_id)?,
+        ThrottledSend::Queued
+    ));
+    assert_eq!(throttle.queue_depth(&session_id), 3);
+    assert!(throttle.next_flush_in().is_some_and(|x| x > Duration::ZERO));
+    assert_eq!(throttle.flush().sent, 0);
+
+    while throttle.queue_depth(&session_id) > 0 {
+        thread::sleep(throttle.next_flush_in().unwrap());
+        assert!(throttle.flush().errors.is_empty());
+    }
+    assert_eq!(throttle.next_flush_in(), None);
+    assert_eq!(
+        throttle.metrics(&session_id),
+        ThrottleMetrics {
+            queue_depth: 0,
+            max_queue_depth: 3,
+            sent: 5,
+            queued: 3,
+            rejected: 0,
+        }
+    );
+    wait_until(|| sender.sent().len() == 5);
+    assert_eq!(sender.sent(), ["N1", "N2", "F", "N3", "N4"]);
+
+    // Reject: messages over the limit are given back.
+    let throttle = OutboundThrottle::try_new(1, Duration::from_secs(60), ThrottlePolicy::Reject)?;
+    assert!(matches!(
+        throttle.send(build_news("R1", &[])?, &session_id)?,
+        ThrottledSend::Sent
+    ));
+    match throttle.send(build_news("R2", &[])?, &session_id)? {
+        ThrottledSend::Rejected(msg) => assert_eq!(msg.get_field(MSG_HEADLINE).unwrap(), "R2"),
+        status => panic!("Unexpected status: {status:?}"),
+    }
+    assert_eq!(throttle.metrics(&session_id).rejected, 1);
+    assert_eq!(throttle.queue_depth(&session_id), 0);
+
+    // Block: caller waits for the window to allow message.
+    let throttle = OutboundThrottle::try_new(1, Duration::from_millis(200), ThrottlePolicy::Block)?;
+    let start = Instant::now();
+    for headline in ["B1", "B2"] {
+        assert!(matches!(
+            throttle.send(build_news(headline, &[])?, &session_id)?,
+            ThrottledSend::Sent
+        ));
+    }
+    assert!(start.elapsed() >= Duration::from_millis(150));
+    assert_eq!(throttle.metrics(&session_id).sent, 2);
+
+    // Per session limit: session gets its own limit instead of the throttle one.
+    let throttle = OutboundThrottle::try_new(1, Duration::from_secs(60), ThrottlePolicy::Reject)?;
+    throttle.set_limit(&session_id, 2, Duration::from_secs(60))?;
+    for (headline, expect_sent) in [("L1", true), ("L2", true), ("L3", false)] {
+        let status = throttle.send(build_news(headline, &[])?, &session_id)?;
+        assert_eq!(matches!(status, ThrottledSend::Sent), expect_sent);
+    }
+
+    wait_until(|| sender.sent().len() == 10);
+    assert_eq!(sender.sent()[5..], ["R1", "B1", "B2", "L1", "L2"]);
+
+    // Unknown session.
+    let unknown = SessionId::try_new("FIX.4.4", "FOO", "BAR", "")?;
+    assert!(matches!(
+        throttle.send(build_news("X", &[])?, &unknown),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+    // Failed message is not counted and is kept for next flush.
+    assert_eq!(throttle.metrics(&unknown).sent, 0);
+    assert_eq!(throttle.queue_depth(&unknown), 1);
+    let flush = throttle.flush();
+    assert_eq!(flush.sent, 0);
+    assert_eq!(flush.errors.len(), 1);
+    assert_eq!(flush.errors[0].0.to_repr(), unknown.to_repr());
+    assert!(matches!(
+        flush.errors[0].1,
+        QuickFixError::SessionNotFound(_)
+    ));
+    assert_eq!(throttle.queue_depth(&unknown), 1);
+
+    // Message sent while queued ones fail is kept behind them.
+    assert!(matches!(
+        throttle.send(build_news("Y", &[])?, &unknown),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+    assert_eq!(throttle.queue_depth(&unknown), 2);
+
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+
+    // Reject: messages queued after a failure are sent first, then policy applies.
+    drop(socket_sender);
+    let throttle = OutboundThrottle::try_new(1, Duration::from_secs(60), ThrottlePolicy::Reject)?;
+    for headline in ["Q1", "Q2"] {
+        assert!(matches!(
+            throttle.send(build_news(headline, &[])?, &session_id),
+            Err(QuickFixError::SessionNotFound(_))
+        ));
+    }
+    assert_eq!(throttle.queue_depth(&session_id), 2);
+
+    let _socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        &log_factory,
+        FixSocketServerKind::default(),
+    )?;
+    match throttle.send(build_news("Q3", &[])?, &session_id)? {
+        ThrottledSend::Rejected(msg) => assert_eq!(msg.get_field(MSG_HEADLINE).unwrap(), "Q3"),
+        status => panic!("Unexpected status: {status:?}"),
+    }
+    assert_eq!(
+        throttle.metrics(&session_id),
+        ThrottleMetrics {
+            queue_depth: 1,
+            max_queue_depth: 2,
+            sent: 1,
+            queued: 1,
+            rejected: 1,
+        }
+    );
+
+    Ok(())
+}
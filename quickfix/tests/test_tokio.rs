@@ -0,0 +1,121 @@
+#![cfg(feature = "tokio")]
+
+use std::{future::poll_fn, pin::Pin, sync::Arc, time::Duration};
+
+use futures_core::Stream;
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+fn event_name(event: &FixEvent) -> String {
+    match event {
+        FixEvent::Create(_) => "Create".to_string(),
+        FixEvent::Logon(_) => "Logon".to_string(),
+        FixEvent::Logout(_) => "Logout".to_string(),
+        FixEvent::ToAdmin(msg, _) => format!("ToAdmin:{}", msg.msg_type().unwrap()),
+        FixEvent::ToApp(msg, _) => format!("ToApp:{}", msg.msg_type().unwrap()),
+        FixEvent::FromAdmin(msg, _) => format!("FromAdmin:{}", msg.msg_type().unwrap()),
+        FixEvent::FromApp(msg, _, _) => format!("FromApp:{}", msg.msg_type().unwrap()),
+    }
+}
+
+#[test]
+fn test_async_sender_builder() {
+    assert!(AsyncSender::try_new(0).is_err());
+    assert_eq!(AsyncSender::try_new(3).unwrap().available_slots(), 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_async_engine() -> Result<(), QuickFixError> {
+    let communication_port = find_available_port();
+    let (sender, mut events) = ChannelApplication::new_async(64);
+
+    let mut acceptor = FixEngine::try_new_acceptor(
+        build_settings(ServerType::Receiver, communication_port)?,
+        Arc::new(NullFixApplication),
+        MemoryMessageStoreFactory::new(),
+        Arc::new(NullLogger),
+        FixSocketServerKind::default(),
+    )?;
+    let mut initiator = FixEngine::try_new_initiator(
+        build_settings(ServerType::Sender, communication_port)?,
+        Arc::new(sender),
+        MemoryMessageStoreFactory::new(),
+        Arc::new(NullLogger),
+        FixSocketServerKind::default(),
+    )?;
+
+    // Nothing to log on to yet.
+    acceptor.start()?;
+    let receiver_id = ServerType::Receiver.session_id();
+    assert!(
+        !acceptor
+            .wait_logged_on(&receiver_id, Duration::from_millis(100))
+            .await?
+    );
+
+    // Session not created yet is not logged on.
+    let unknown = SessionId::try_new("FIX.4.4", "FOO", "BAR", "")?;
+    assert!(
+        !acceptor
+            .wait_logged_on(&unknown, Duration::from_millis(100))
+            .await?
+    );
+
+    // Wait for logon.
+    initiator.start()?;
+    let sender_id = ServerType::Sender.session_id();
+    assert!(
+        initiator
+            .wait_logged_on(&sender_id, Duration::from_secs(10))
+            .await?
+    );
+    assert!(
+        acceptor
+            .wait_logged_on(&receiver_id, Duration::from_secs(10))
+            .await?
+    );
+
+    // Events can be consumed using stream API or directly.
+    let mut names = Vec::new();
+    while !names.contains(&"Logon".to_string()) {
+        let event = poll_fn(|cx| Pin::new(&mut events).poll_next(cx)).await;
+        names.push(event_name(&event.unwrap()));
+    }
+    assert_eq!(names, ["Create", "ToAdmin:A", "FromAdmin:A", "Logon"]);
+
+    // Send messages concurrently.
+    let async_sender = AsyncSender::try_new(2)?;
+    let tasks: Vec<_> = (0..5)
+        .map(|idx| {
+            let async_sender = async_sender.clone();
+            let sender_id = sender_id.clone();
+            tokio::spawn(async move {
+                let msg = build_news(&format!("Hello {idx}"), &[])?;
+                async_sender.send(msg, &sender_id).await
+            })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap()?;
+    }
+    assert_eq!(async_sender.available_slots(), 2);
+
+    for _ in 0..5 {
+        let event = events.recv().await.unwrap();
+        assert_eq!(event_name(&event), "ToApp:B");
+    }
+
+    // Shutdown engines, and consume remaining events until application is dropped.
+    initiator.shutdown().await?;
+    acceptor.shutdown().await?;
+
+    let mut names = Vec::new();
+    while let Some(event) = events.recv().await {
+        names.push(event_name(&event));
+    }
+    assert!(names.contains(&"Logout".to_string()));
+
+    Ok(())
+}
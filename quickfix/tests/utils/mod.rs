@@ -1,7 +1,11 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
-use std::net::TcpListener;
+use std::{
+    net::TcpListener,
+    thread,
+    time::{Duration, Instant},
+};
 
 pub mod checker;
 mod msg_const;
@@ -20,6 +24,14 @@ pub fn find_available_port() -> u16 {
         .expect("No port available in test range")
 }
 
+pub fn wait_until<F: FnMut() -> bool>(mut condition: F) {
+    let start = Instant::now();
+    while !condition() {
+        assert!(start.elapsed() < Duration::from_secs(10), "Timeout");
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
 pub struct NullFixApplication;
 
 impl ApplicationCallback for NullFixApplication {}